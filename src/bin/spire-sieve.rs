@@ -0,0 +1,367 @@
+use std::{fmt, io, path::PathBuf, process::ExitCode};
+
+use clap::{Args, Parser, Subcommand};
+use spire_sieve::{
+    card::{reward::CardRewarder, Card},
+    character::Character,
+    map::{
+        assign_nodes::buffed_elite::{EliteBuff, BURNING_ELITE_ACTS},
+        in_neighborhood::in_vec::InVec,
+        out_neighborhood::out_vec::OutVec,
+        Act, Map,
+    },
+    neow::{
+        contents::{NeowContent, NeowContents},
+        Blessing, NeowBonuses, MINI_BLESSING,
+    },
+    relic::{pool::RelicPools, Tier},
+    rng::RunRng,
+    seed::{Seed, SeedString},
+    settings::{FilterSettings, SearchSettings, SeedBound},
+    sieve::{
+        checkpoint::Checkpoint,
+        sink::{FirstN, Print, Sink, WriteSink},
+        Sieve, Summary,
+    },
+    unlock::Unlocks,
+};
+
+#[derive(Parser)]
+#[command(version, about = "Sieve for Slay the Spire seeds")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Search a seed range for seeds passing every filter
+    Sieve(Box<SieveArgs>),
+    /// Print the map, Neow bonuses, card rewards, relics and burning elites of one seed
+    Report(ReportArgs),
+    /// Convert a seed string to its numeric seed, or back with `--number`
+    Convert(ConvertArgs),
+}
+
+#[derive(Args)]
+struct SieveArgs {
+    /// `SearchSettings` JSON file; the flags below override or extend it
+    #[arg(long)]
+    settings: Option<PathBuf>,
+    /// First seed string of the range
+    #[arg(long)]
+    start: Option<String>,
+    /// Last seed string of the range
+    #[arg(long)]
+    end: Option<String>,
+    /// Character whose card pools are used, e.g. `silent`
+    #[arg(long, value_parser = parse_character)]
+    character: Option<Character>,
+    /// Generate maps with ascension elite chances; `--ascension=false` turns them off
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    ascension: Option<bool>,
+    /// Expect Neow's mini blessing, offered when the previous run did not reach a boss
+    #[arg(long)]
+    mini_blessing: bool,
+    /// Read unlock levels from the game's `preferences` directory
+    #[arg(long, value_name = "DIR")]
+    preferences: Option<PathBuf>,
+    /// Require a single node on this floor
+    #[arg(long, value_name = "FLOOR")]
+    bottleneck: Option<usize>,
+    /// Require a bottleneck on this floor and a burning elite in act I
+    #[arg(long, value_name = "FLOOR")]
+    burning_elite: Option<usize>,
+    /// Accepted burning elite buffs, e.g. `Strength`
+    #[arg(long = "buff", value_parser = parse_name::<EliteBuff>)]
+    buffs: Vec<EliteBuff>,
+    /// Reject seeds offering this card in the first three card rewards, e.g. `Prepared`
+    #[arg(long = "reject-card", value_parser = parse_name::<Card>)]
+    rejected_cards: Vec<Card>,
+    /// Write matching seeds to this file instead of stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+    /// Stop after this many matches
+    #[arg(long)]
+    first: Option<usize>,
+    /// Resume from and periodically save to this checkpoint file
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+    /// Seeds tested between checkpoint saves
+    #[arg(long, default_value_t = 1 << 24)]
+    every: u64,
+}
+
+#[derive(Args)]
+struct ReportArgs {
+    seed: String,
+    #[arg(long, value_parser = parse_character, default_value = "ironclad")]
+    character: Character,
+    /// Generate maps with ascension elite chances
+    #[arg(long)]
+    ascension: bool,
+    /// Expect Neow's mini blessing, offered when the previous run did not reach a boss
+    #[arg(long)]
+    mini_blessing: bool,
+}
+
+#[derive(Args)]
+struct ConvertArgs {
+    #[arg(allow_negative_numbers = true)]
+    seed: String,
+    /// Read `seed` as a numeric seed and print its seed string
+    #[arg(long)]
+    number: bool,
+}
+
+#[derive(Debug)]
+enum Error {
+    Io(io::Error),
+    Settings(spire_sieve::settings::Error),
+    Checkpoint(spire_sieve::sieve::checkpoint::Error),
+    Preferences(spire_sieve::unlock::preferences::Error),
+    ParseSeedString(spire_sieve::seed::from::Error),
+    ParseSeed(std::num::ParseIntError),
+    MissingRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::Settings(error) => write!(f, "invalid search settings: {error:?}"),
+            Error::Checkpoint(error) => write!(f, "cannot resume checkpoint: {error:?}"),
+            Error::Preferences(error) => write!(f, "cannot read unlocks: {error:?}"),
+            Error::ParseSeedString(error) => write!(f, "invalid seed string: {error:?}"),
+            Error::ParseSeed(error) => write!(f, "invalid seed: {error}"),
+            Error::MissingRange => {
+                write!(f, "`--start` and `--end` are required without `--settings`")
+            }
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<spire_sieve::settings::Error> for Error {
+    fn from(error: spire_sieve::settings::Error) -> Self {
+        Self::Settings(error)
+    }
+}
+
+impl From<spire_sieve::sieve::checkpoint::Error> for Error {
+    fn from(error: spire_sieve::sieve::checkpoint::Error) -> Self {
+        Self::Checkpoint(error)
+    }
+}
+
+fn parse_character(s: &str) -> Result<Character, String> {
+    s.to_uppercase()
+        .parse()
+        .map_err(|_| format!("unknown character `{s}`"))
+}
+
+// accepts the variant names used in `SearchSettings` JSON
+fn parse_name<T: serde::de::DeserializeOwned>(s: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(s.into())).map_err(|error| error.to_string())
+}
+
+fn parse_seed_string(s: &str) -> Result<Seed, Error> {
+    let seed: SeedString = s.parse().map_err(Error::ParseSeedString)?;
+    Ok(seed.into())
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Sieve(args) => sieve(*args),
+        Command::Report(args) => report(args),
+        Command::Convert(args) => convert(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl SieveArgs {
+    fn settings(&self) -> Result<SearchSettings, Error> {
+        let mut filters = Vec::new();
+        if let Some(floor) = self.bottleneck {
+            filters.push(FilterSettings::Bottleneck { floor });
+        }
+        if let Some(floor) = self.burning_elite {
+            let buffs = (!self.buffs.is_empty()).then(|| self.buffs.clone());
+            filters.push(FilterSettings::BurningElite { floor, buffs });
+        }
+        if !self.rejected_cards.is_empty() {
+            filters.push(FilterSettings::RejectedCards(self.rejected_cards.clone()));
+        }
+        let start = self.start.clone().map(SeedBound::SeedString);
+        let end = self.end.clone().map(SeedBound::SeedString);
+        let mut settings = match &self.settings {
+            Some(path) => SearchSettings::from_json(&std::fs::read_to_string(path)?)?,
+            None => SearchSettings {
+                start: start.clone().ok_or(Error::MissingRange)?,
+                end: end.clone().ok_or(Error::MissingRange)?,
+                character: Character::Ironclad,
+                ascension: false,
+                unlocks: None,
+                blessing: Blessing::Full,
+                filter: FilterSettings::All(Vec::new()),
+            },
+        };
+        settings.start = start.unwrap_or(settings.start);
+        settings.end = end.unwrap_or(settings.end);
+        settings.character = self.character.unwrap_or(settings.character);
+        settings.ascension = self.ascension.unwrap_or(settings.ascension);
+        if self.mini_blessing {
+            settings.blessing = Blessing::Mini;
+        }
+        if let Some(dir) = &self.preferences {
+            settings.unlocks = Some(Unlocks::from_preferences(dir).map_err(Error::Preferences)?);
+        }
+        if !filters.is_empty() {
+            filters.insert(0, settings.filter);
+            settings.filter = FilterSettings::All(filters);
+        }
+        Ok(settings)
+    }
+}
+
+fn sieve(args: SieveArgs) -> Result<(), Error> {
+    let settings = args.settings()?;
+    let sieve = settings.sieve()?;
+    let mut file = args.out.as_ref().map(WriteSink::create).transpose()?;
+    let mut print = Print;
+    let sink: &mut dyn Sink = match &mut file {
+        Some(file) => file,
+        None => &mut print,
+    };
+    let summary = match args.first {
+        Some(n) => run(&sieve, &settings, &args, FirstN::new(n, sink))?,
+        None => run(&sieve, &settings, &args, sink)?,
+    };
+    if let Some(file) = file {
+        file.finish()?;
+    }
+    eprintln!(
+        "tested {} seeds, {} matched",
+        summary.tested, summary.matched
+    );
+    Ok(())
+}
+
+fn run<F, S>(
+    sieve: &Sieve<F>,
+    settings: &SearchSettings,
+    args: &SieveArgs,
+    sink: S,
+) -> Result<Summary, Error>
+where
+    F: spire_sieve::filter::SeedFilter + Sync,
+    S: Sink,
+{
+    let Some(path) = &args.checkpoint else {
+        #[cfg(feature = "parallel")]
+        return Ok(sieve.par_run(sink));
+        #[cfg(not(feature = "parallel"))]
+        return Ok(sieve.run(sink));
+    };
+    let mut checkpoint = Checkpoint::open(path, settings.to_json()?)?;
+    let mut sink = sink;
+    if let Some(last) = checkpoint.last_completed() {
+        eprintln!("resuming after {}", SeedString::from(last));
+        // hits from earlier runs are reported again so the output is complete
+        for hit in checkpoint.hits() {
+            if sink.push(hit.clone()).is_break() {
+                return Ok(Summary::default());
+            }
+        }
+    }
+    #[cfg(feature = "parallel")]
+    let summary = sieve.par_run_checkpointed(&mut checkpoint, args.every, sink)?;
+    #[cfg(not(feature = "parallel"))]
+    let summary = sieve.run_checkpointed(&mut checkpoint, args.every, sink)?;
+    Ok(summary)
+}
+
+fn report(args: ReportArgs) -> Result<(), Error> {
+    let seed = parse_seed_string(&args.seed)?;
+    println!("seed {}", SeedString::from(seed.clone()));
+
+    let mut rng = RunRng::new(&seed);
+    let map: Map<6, InVec, OutVec> = Map::generate(&mut rng.map(Act::One), args.ascension);
+    println!("\n{} map:{map}", Act::One);
+
+    println!("\nNeow bonuses:");
+    if args.mini_blessing {
+        for option in MINI_BLESSING {
+            println!("  {option:?}");
+        }
+    } else {
+        let bonuses = NeowBonuses::generate_rng(&mut rng.neow);
+        let contents = NeowContents::generate(&bonuses, &seed, args.character, None);
+        let content = |content: Option<NeowContent>| match content {
+            Some(content) => format!(": {content:?}"),
+            None => String::new(),
+        };
+        let (drawback, third) = bonuses.third;
+        println!("  {:?}{}", bonuses.first, content(contents.first));
+        println!("  {:?}{}", bonuses.second, content(contents.second));
+        println!("  {drawback:?}, {third:?}{}", content(contents.third));
+        println!("  boss swap: {}", contents.boss_swap.id());
+    }
+
+    let rewarder: CardRewarder<'_, 3> = CardRewarder::new(args.character, None);
+    println!("\n{:?} card rewards:", args.character);
+    for (i, reward) in rewarder.generate_rewards(&mut rng.card).iter().enumerate() {
+        let [a, b, c] = reward;
+        println!("  {}: {a}, {b}, {c}", i + 1);
+    }
+
+    let relics = RelicPools::generate(args.character, &mut rng.relic);
+    println!("\nfirst relics:");
+    for tier in [Tier::Common, Tier::Boss] {
+        let first = relics.first(tier).map(|relic| relic.id());
+        println!("  {tier:?}: {}", first.unwrap_or("none"));
+    }
+
+    println!("\nburning elites:");
+    let elites = Map::<6, InVec, OutVec>::burning_elites(&seed, args.ascension);
+    for (act, elite) in BURNING_ELITE_ACTS.iter().zip(elites) {
+        match elite {
+            Some(elite) => println!(
+                "  {act}: {:?} on floor {}, position {}",
+                elite.buff,
+                elite.buff_y + 1,
+                elite.buff_x
+            ),
+            None => println!("  {act}: none"),
+        }
+    }
+    Ok(())
+}
+
+fn convert(args: ConvertArgs) -> Result<(), Error> {
+    if args.number {
+        let seed: i64 = args.seed.parse().map_err(Error::ParseSeed)?;
+        println!("{}", SeedString::from(Seed::from(seed)));
+    } else {
+        let seed = parse_seed_string(&args.seed)?;
+        println!("{}", seed.seed());
+    }
+    Ok(())
+}
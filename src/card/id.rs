@@ -0,0 +1,407 @@
+use super::{Card, Kind};
+
+impl Card {
+    // the game's card id, which `getRandomCard` sorts by before picking
+    pub const fn id(self) -> &'static str {
+        use Card::*;
+        match self {
+            Invalid => "",
+            SwordBoomerang => "Sword Boomerang",
+            PerfectedStrike => "Perfected Strike",
+            HeavyBlade => "Heavy Blade",
+            WildStrike => "Wild Strike",
+            Headbutt => "Headbutt",
+            Havoc => "Havoc",
+            Armaments => "Armaments",
+            Clothesline => "Clothesline",
+            TwinStrike => "Twin Strike",
+            PommelStrike => "Pommel Strike",
+            Thunderclap => "Thunderclap",
+            Clash => "Clash",
+            ShrugItOff => "Shrug It Off",
+            TrueGrit => "True Grit",
+            BodySlam => "Body Slam",
+            IronWave => "Iron Wave",
+            Flex => "Flex",
+            Warcry => "Warcry",
+            Cleave => "Cleave",
+            Anger => "Anger",
+            Evolve => "Evolve",
+            Uppercut => "Uppercut",
+            GhostlyArmor => "Ghostly Armor",
+            FireBreathing => "Fire Breathing",
+            Dropkick => "Dropkick",
+            Carnage => "Carnage",
+            Bloodletting => "Bloodletting",
+            Rupture => "Rupture",
+            SecondWind => "Second Wind",
+            SearingBlow => "Searing Blow",
+            BattleTrance => "Battle Trance",
+            Sentinel => "Sentinel",
+            Entrench => "Entrench",
+            Rage => "Rage",
+            FeelNoPain => "Feel No Pain",
+            Disarm => "Disarm",
+            SeeingRed => "Seeing Red",
+            DarkEmbrace => "Dark Embrace",
+            Combust => "Combust",
+            Whirlwind => "Whirlwind",
+            SeverSoul => "Sever Soul",
+            Rampage => "Rampage",
+            Shockwave => "Shockwave",
+            Metallicize => "Metallicize",
+            BurningPact => "Burning Pact",
+            Pummel => "Pummel",
+            FlameBarrier => "Flame Barrier",
+            BloodForBlood => "Blood for Blood",
+            Intimidate => "Intimidate",
+            Hemokinesis => "Hemokinesis",
+            RecklessCharge => "Reckless Charge",
+            InfernalBlade => "Infernal Blade",
+            DualWield => "Dual Wield",
+            PowerThrough => "Power Through",
+            Inflame => "Inflame",
+            SpotWeakness => "Spot Weakness",
+            DoubleTap => "Double Tap",
+            DemonForm => "Demon Form",
+            Bludgeon => "Bludgeon",
+            Feed => "Feed",
+            LimitBreak => "Limit Break",
+            Corruption => "Corruption",
+            Barricade => "Barricade",
+            FiendFire => "Fiend Fire",
+            Berserk => "Berserk",
+            Impervious => "Impervious",
+            Juggernaut => "Juggernaut",
+            Brutality => "Brutality",
+            Reaper => "Reaper",
+            Exhume => "Exhume",
+            Offering => "Offering",
+            Immolate => "Immolate",
+            FlyingKnee => "Flying Knee",
+            DodgeAndRoll => "Dodge and Roll",
+            SuckerPunch => "Sucker Punch",
+            PiercingWail => "PiercingWail",
+            Prepared => "Prepared",
+            Outmaneuver => "Outmaneuver",
+            Backflip => "Backflip",
+            Slice => "Slice",
+            QuickSlash => "Quick Slash",
+            Acrobatics => "Acrobatics",
+            PoisonedStab => "Poisoned Stab",
+            DaggerThrow => "Dagger Throw",
+            Deflect => "Deflect",
+            BladeDance => "Blade Dance",
+            Bane => "Bane",
+            DaggerSpray => "Dagger Spray",
+            DeadlyPoison => "Deadly Poison",
+            SneakyStrike => "Underhanded Strike",
+            CloakAndDagger => "Cloak And Dagger",
+            Predator => "Predator",
+            AllOutAttack => "All Out Attack",
+            Distraction => "Distraction",
+            Footwork => "Footwork",
+            Accuracy => "Accuracy",
+            MasterfulStab => "Masterful Stab",
+            Flechettes => "Flechettes",
+            Concentrate => "Concentrate",
+            BouncingFlask => "Bouncing Flask",
+            Backstab => "Backstab",
+            Dash => "Dash",
+            Eviscerate => "Eviscerate",
+            Reflex => "Reflex",
+            InfiniteBlades => "Infinite Blades",
+            NoxiousFumes => "Noxious Fumes",
+            HeelHook => "Heel Hook",
+            Terror => "Terror",
+            WellLaidPlans => "Well Laid Plans",
+            Finisher => "Finisher",
+            EscapePlan => "Escape Plan",
+            CalculatedGamble => "Calculated Gamble",
+            Skewer => "Skewer",
+            RiddleWithHoles => "Riddle With Holes",
+            EndlessAgony => "Endless Agony",
+            Setup => "Setup",
+            Blur => "Blur",
+            Caltrops => "Caltrops",
+            Choke => "Choke",
+            Expertise => "Expertise",
+            Tactician => "Tactician",
+            Catalyst => "Catalyst",
+            LegSweep => "Leg Sweep",
+            CripplingCloud => "Crippling Poison",
+            Alchemize => "Venomology",
+            CorpseExplosion => "Corpse Explosion",
+            Malaise => "Malaise",
+            PhantasmalKiller => "Phantasmal Killer",
+            DieDieDie => "Die Die Die",
+            Adrenaline => "Adrenaline",
+            Envenom => "Envenom",
+            Doppelganger => "Doppelganger",
+            Burst => "Burst",
+            WraithForm => "Wraith Form v2",
+            ToolsOfTheTrade => "Tools of the Trade",
+            Nightmare => "Night Terror",
+            Unload => "Unload",
+            AfterImage => "After Image",
+            BulletTime => "Bullet Time",
+            StormOfSteel => "Storm of Steel",
+            GlassKnife => "Glass Knife",
+            AThousandCuts => "A Thousand Cuts",
+            GrandFinale => "Grand Finale",
+            SteamBarrier => "Steam",
+            ColdSnap => "Cold Snap",
+            Leap => "Leap",
+            BeamCell => "Beam Cell",
+            Hologram => "Hologram",
+            ChargeBattery => "Conserve Battery",
+            SweepingBeam => "Sweeping Beam",
+            Turbo => "Turbo",
+            Coolheaded => "Coolheaded",
+            Claw => "Gash",
+            Rebound => "Rebound",
+            Stack => "Stack",
+            Barrage => "Barrage",
+            CompileDriver => "Compile Driver",
+            Recursion => "Redo",
+            Streamline => "Streamline",
+            BallLightning => "Ball Lightning",
+            GoForTheEyes => "Go for the Eyes",
+            DoomAndGloom => "Doom and Gloom",
+            Defragment => "Defragment",
+            Capacitor => "Capacitor",
+            WhiteNoise => "White Noise",
+            Skim => "Skim",
+            Recycle => "Recycle",
+            Scrape => "Scrape",
+            Bullseye => "Lockon",
+            Reprogram => "Reprogram",
+            AutoShields => "Auto Shields",
+            ReinforcedBody => "Reinforced Body",
+            DoubleEnergy => "Double Energy",
+            Darkness => "Darkness",
+            RipAndTear => "Rip and Tear",
+            Ftl => "FTL",
+            ForceField => "Force Field",
+            Equilibrium => "Undo",
+            Tempest => "Tempest",
+            Heatsinks => "Heatsinks",
+            StaticDischarge => "Static Discharge",
+            BootSequence => "BootSequence",
+            Chill => "Chill",
+            Loop => "Loop",
+            SelfRepair => "Self Repair",
+            Melter => "Melter",
+            Chaos => "Chaos",
+            Blizzard => "Blizzard",
+            Aggregate => "Aggregate",
+            Fusion => "Fusion",
+            Consume => "Consume",
+            Glacier => "Glacier",
+            Sunder => "Sunder",
+            HelloWorld => "Hello World",
+            Overclock => "Steam Power",
+            GeneticAlgorithm => "Genetic Algorithm",
+            Storm => "Storm",
+            MultiCast => "Multi-Cast",
+            Hyperbeam => "Hyperbeam",
+            ThunderStrike => "Thunder Strike",
+            BiasedCognition => "Biased Cognition",
+            MachineLearning => "Machine Learning",
+            Electrodynamics => "Electrodynamics",
+            Buffer => "Buffer",
+            Rainbow => "Rainbow",
+            Seek => "Seek",
+            MeteorStrike => "Meteor Strike",
+            EchoForm => "Echo Form",
+            AllForOne => "All For One",
+            Reboot => "Reboot",
+            Amplify => "Amplify",
+            CreativeAi => "Creative AI",
+            Fission => "Fission",
+            CoreSurge => "Core Surge",
+            EmptyFist => "EmptyFist",
+            Prostrate => "Prostrate",
+            Evaluate => "Evaluate",
+            CrushJoints => "CrushJoints",
+            PressurePoints => "PathToVictory",
+            FollowUp => "FollowUp",
+            CutThroughFate => "CutThroughFate",
+            SashWhip => "SashWhip",
+            EmptyBody => "EmptyBody",
+            Tranquility => "ClearTheMind",
+            Crescendo => "Crescendo",
+            ThirdEye => "ThirdEye",
+            Protect => "Protect",
+            FlurryOfBlows => "FlurryOfBlows",
+            JustLucky => "JustLucky",
+            Halt => "Halt",
+            FlyingSleeves => "FlyingSleeves",
+            BowlingBash => "BowlingBash",
+            Consecrate => "Consecrate",
+            Pray => "Pray",
+            SignatureMove => "SignatureMove",
+            Weave => "Weave",
+            EmptyMind => "EmptyMind",
+            Nirvana => "Nirvana",
+            Tantrum => "Tantrum",
+            Conclude => "Conclude",
+            Worship => "Worship",
+            Swivel => "Swivel",
+            Perseverance => "Perseverance",
+            Meditate => "Meditate",
+            Study => "Study",
+            WaveOfTheHand => "WaveOfTheHand",
+            SandsOfTime => "SandsOfTime",
+            FearNoEvil => "FearNoEvil",
+            ReachHeaven => "ReachHeaven",
+            MentalFortress => "MentalFortress",
+            DeceiveReality => "DeceiveReality",
+            Rushdown => "Adaptation",
+            InnerPeace => "InnerPeace",
+            Collect => "Collect",
+            WreathOfFlame => "WreathOfFlame",
+            Wallop => "Wallop",
+            CarveReality => "CarveReality",
+            Fasting => "Fasting2",
+            LikeWater => "LikeWater",
+            ForeignInfluence => "ForeignInfluence",
+            WindmillStrike => "WindmillStrike",
+            Indignation => "Indignation",
+            BattleHymn => "BattleHymn",
+            TalkToTheHand => "TalkToTheHand",
+            Sanctity => "Sanctity",
+            Foresight => "Wireheading",
+            SimmeringFury => "Vengeance",
+            WheelKick => "WheelKick",
+            Judgment => "Judgement",
+            ConjureBlade => "ConjureBlade",
+            MasterReality => "MasterReality",
+            Brilliance => "Brilliance",
+            Devotion => "Devotion",
+            Blasphemy => "Blasphemy",
+            Ragnarok => "Ragnarok",
+            LessonLearned => "LessonLearned",
+            Scrawl => "Scrawl",
+            Vault => "Vault",
+            Alpha => "Alpha",
+            Wish => "Wish",
+            Omniscience => "Omniscience",
+            Establishment => "Establishment",
+            SpiritShield => "SpiritShield",
+            DevaForm => "DevaForm",
+            DeusExMachina => "DeusExMachina",
+            BandageUp => "Bandage Up",
+            Blind => "Blind",
+            DarkShackles => "Dark Shackles",
+            DeepBreath => "Deep Breath",
+            Discovery => "Discovery",
+            DramaticEntrance => "Dramatic Entrance",
+            Enlightenment => "Enlightenment",
+            Finesse => "Finesse",
+            FlashOfSteel => "Flash of Steel",
+            Forethought => "Forethought",
+            GoodInstincts => "Good Instincts",
+            Impatience => "Impatience",
+            JackOfAllTrades => "Jack Of All Trades",
+            Madness => "Madness",
+            MindBlast => "Mind Blast",
+            Panacea => "Panacea",
+            PanicButton => "PanicButton",
+            Purity => "Purity",
+            SwiftStrike => "Swift Strike",
+            Trip => "Trip",
+            Apotheosis => "Apotheosis",
+            Chrysalis => "Chrysalis",
+            HandOfGreed => "HandOfGreed",
+            Magnetism => "Magnetism",
+            MasterOfStrategy => "Master of Strategy",
+            Mayhem => "Mayhem",
+            Metamorphosis => "Metamorphosis",
+            Panache => "Panache",
+            SadisticNature => "Sadistic Nature",
+            SecretTechnique => "Secret Technique",
+            SecretWeapon => "Secret Weapon",
+            TheBomb => "The Bomb",
+            ThinkingAhead => "Thinking Ahead",
+            Transmutation => "Transmutation",
+            Violence => "Violence",
+        }
+    }
+
+    pub const fn kind(self) -> Option<Kind> {
+        use Card::*;
+        match self {
+            Invalid => None,
+            SwordBoomerang | PerfectedStrike | HeavyBlade | WildStrike | Headbutt | Clothesline
+            | TwinStrike | PommelStrike | Thunderclap | Clash | BodySlam | IronWave | Cleave
+            | Anger | Uppercut | Dropkick | Carnage | SearingBlow | Whirlwind | SeverSoul
+            | Rampage | Pummel | BloodForBlood | Hemokinesis | RecklessCharge | Bludgeon | Feed
+            | FiendFire | Reaper | Immolate | FlyingKnee | SuckerPunch | Slice | QuickSlash
+            | PoisonedStab | DaggerThrow | Bane | DaggerSpray | SneakyStrike | Predator
+            | AllOutAttack | MasterfulStab | Flechettes | Backstab | Dash | Eviscerate
+            | HeelHook | Finisher | Skewer | RiddleWithHoles | EndlessAgony | Choke | DieDieDie
+            | Unload | GlassKnife | GrandFinale | ColdSnap | BeamCell | SweepingBeam | Claw
+            | Rebound | Barrage | CompileDriver | Streamline | BallLightning | GoForTheEyes
+            | DoomAndGloom | Scrape | Bullseye | RipAndTear | Ftl | Melter | Blizzard | Sunder
+            | Hyperbeam | ThunderStrike | MeteorStrike | AllForOne | CoreSurge | EmptyFist
+            | CrushJoints | FollowUp | CutThroughFate | SashWhip | FlurryOfBlows | JustLucky
+            | FlyingSleeves | BowlingBash | Consecrate | SignatureMove | Weave | Tantrum
+            | Conclude | SandsOfTime | FearNoEvil | ReachHeaven | Wallop | CarveReality
+            | WindmillStrike | TalkToTheHand | WheelKick | Brilliance | Ragnarok
+            | LessonLearned | DramaticEntrance | FlashOfSteel | MindBlast | SwiftStrike
+            | HandOfGreed => Some(Kind::Attack),
+            Havoc | Armaments | ShrugItOff | TrueGrit | Flex | Warcry | GhostlyArmor
+            | Bloodletting | SecondWind | BattleTrance | Sentinel | Entrench | Rage | Disarm
+            | SeeingRed | Shockwave | BurningPact | FlameBarrier | Intimidate | InfernalBlade
+            | DualWield | PowerThrough | SpotWeakness | DoubleTap | LimitBreak | Impervious
+            | Exhume | Offering | DodgeAndRoll | PiercingWail | Prepared | Outmaneuver
+            | Backflip | Acrobatics | Deflect | BladeDance | DeadlyPoison | CloakAndDagger
+            | Distraction | Concentrate | BouncingFlask | Reflex | Terror | EscapePlan
+            | CalculatedGamble | Setup | Blur | Expertise | Tactician | Catalyst | LegSweep
+            | CripplingCloud | Alchemize | CorpseExplosion | Malaise | PhantasmalKiller
+            | Adrenaline | Doppelganger | Burst | Nightmare | BulletTime | StormOfSteel
+            | SteamBarrier | Leap | Hologram | ChargeBattery | Turbo | Coolheaded | Stack
+            | Recursion | WhiteNoise | Skim | Recycle | Reprogram | AutoShields
+            | ReinforcedBody | DoubleEnergy | Darkness | ForceField | Equilibrium | Tempest
+            | BootSequence | Chill | Chaos | Aggregate | Fusion | Consume | Glacier | Overclock
+            | GeneticAlgorithm | MultiCast | Rainbow | Seek | Reboot | Amplify | Fission
+            | Prostrate | Evaluate | PressurePoints | EmptyBody | Tranquility | Crescendo
+            | ThirdEye | Protect | Halt | Pray | EmptyMind | Worship | Swivel | Perseverance
+            | Meditate | WaveOfTheHand | DeceiveReality | InnerPeace | Collect | WreathOfFlame
+            | ForeignInfluence | Indignation | Sanctity | SimmeringFury | Judgment
+            | ConjureBlade | Blasphemy | Scrawl | Vault | Alpha | Wish | Omniscience
+            | SpiritShield | DeusExMachina | BandageUp | Blind | DarkShackles | DeepBreath
+            | Discovery | Enlightenment | Finesse | Forethought | GoodInstincts | Impatience
+            | JackOfAllTrades | Madness | Panacea | PanicButton | Purity | Trip | Apotheosis
+            | Chrysalis | MasterOfStrategy | Metamorphosis | SecretTechnique | SecretWeapon
+            | TheBomb | ThinkingAhead | Transmutation | Violence => Some(Kind::Skill),
+            Evolve | FireBreathing | Rupture | FeelNoPain | DarkEmbrace | Combust | Metallicize
+            | Inflame | DemonForm | Corruption | Barricade | Berserk | Juggernaut | Brutality
+            | Footwork | Accuracy | InfiniteBlades | NoxiousFumes | WellLaidPlans | Caltrops
+            | Envenom | WraithForm | ToolsOfTheTrade | AfterImage | AThousandCuts | Defragment
+            | Capacitor | Heatsinks | StaticDischarge | Loop | SelfRepair | HelloWorld | Storm
+            | BiasedCognition | MachineLearning | Electrodynamics | Buffer | EchoForm
+            | CreativeAi | Nirvana | Study | MentalFortress | Rushdown | Fasting | LikeWater
+            | BattleHymn | Foresight | MasterReality | Devotion | Establishment | DevaForm
+            | Magnetism | Mayhem | Panache | SadisticNature => Some(Kind::Power),
+        }
+    }
+
+    // the cards whose constructors add `CardTags.HEALING`, so never generated in combat or by a
+    // transform; Bite, Ritual Dagger and Apparition are also tagged but never offered
+    pub const fn is_healing(self) -> bool {
+        use Card::*;
+        matches!(
+            self,
+            Feed | Reaper
+                | Alchemize
+                | SelfRepair
+                | GeneticAlgorithm
+                | LessonLearned
+                | Wish
+                | BandageUp
+                | HandOfGreed
+        )
+    }
+}
@@ -1,12 +1,16 @@
+mod id;
 pub mod pandoras_box;
 pub mod pool;
 pub mod reward;
+pub mod transform;
 
 use strum::EnumCount;
 use strum_macros::EnumCount;
 use strum_macros::EnumIter;
 use strum_macros::FromRepr;
 
+use crate::character::Character;
+
 pub struct CardSlice<'a> {
     pub slice: &'a [Card],
 }
@@ -90,12 +94,67 @@ pub enum Rarity {
     Rare,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr, EnumIter, EnumCount)]
+pub enum Kind {
+    Attack,
+    Skill,
+    Power,
+}
+
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr, EnumIter, EnumCount)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+    Purple,
+    Colorless,
+}
+
+impl Color {
+    pub const fn of(character: Character) -> Self {
+        match character {
+            Character::Ironclad => Color::Red,
+            Character::Silent => Color::Green,
+            Character::Defect => Color::Blue,
+            Character::Watcher => Color::Purple,
+        }
+    }
+
+    pub const fn character(self) -> Option<Character> {
+        match self {
+            Color::Red => Some(Character::Ironclad),
+            Color::Green => Some(Character::Silent),
+            Color::Blue => Some(Character::Defect),
+            Color::Purple => Some(Character::Watcher),
+            Color::Colorless => None,
+        }
+    }
+}
+
 impl Card {
     const fn equal_as_usize(self, other: &Card) -> bool {
         self as usize == *other as usize
     }
+
+    // each color's cards are contiguous; `Invalid` counts as colorless
+    pub const fn color(self) -> Color {
+        let card = self as usize;
+        if card == Card::Invalid as usize || card >= Card::BandageUp as usize {
+            Color::Colorless
+        } else if card < Card::FlyingKnee as usize {
+            Color::Red
+        } else if card < Card::SteamBarrier as usize {
+            Color::Green
+        } else if card < Card::EmptyFist as usize {
+            Color::Blue
+        } else {
+            Color::Purple
+        }
+    }
 }
 
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Eq, PartialEq, FromRepr, EnumIter, EnumCount, Clone, Copy, Default)]
 pub enum Card {
     #[default]
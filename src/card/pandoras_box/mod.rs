@@ -1,63 +1,120 @@
-use core::array;
-
 use libgdx_xs128::{rng::Random, RandomXS128};
 
-use crate::{character::Character, filter::SeedFilter, seed};
+use crate::{
+    character::{basics, Character},
+    filter::RngFilter,
+    seed,
+    unlock::Unlocks,
+};
+
+use super::{
+    transform::{TransformPool, TransformSource, TransformedCards, Transformer, MAX_BASICS},
+    Card,
+};
 
-use super::Card;
+// Neow's boss swap, before the first floor
+const NEOW_FLOOR: usize = 0;
 
-pub struct PandorasBox<'a> {
-    cards: &'a [Card],
+pub struct PandorasBox {
+    transformer: Transformer,
+    // every strike and defend; Ascender's Bane from ascension 10 is a curse and stays
+    basics: usize,
 }
 
-impl<'a> PandorasBox<'a> {
-    pub const fn new(cards: &'a [Card]) -> Self {
-        Self { cards }
+impl PandorasBox {
+    pub const fn new(character: Character, unlocks: Option<Unlocks>) -> Self {
+        Self {
+            transformer: Transformer::new(
+                character,
+                unlocks,
+                TransformSource::PandorasBox { floor: NEOW_FLOOR },
+            ),
+            basics: basics(character),
+        }
+    }
+
+    // the floor the box is taken on, 17 from the Act I boss chest
+    pub const fn with_floor(self, floor: usize) -> Self {
+        Self {
+            transformer: self
+                .transformer
+                .with_source(TransformSource::PandorasBox { floor }),
+            ..self
+        }
     }
 
-    pub fn pandoras_box(&self, seed: &seed::Seed) -> [Card; 9] {
-        let n = self.cards.len();
-        let mut rng = Random::new(seed.seed as u64);
-        array::from_fn(|_| self.cards[rng.next_capped_u64(n as u64) as usize])
+    pub fn pandoras_box(&self, seed: &seed::Seed) -> TransformedCards {
+        let basics = &[Card::Invalid; MAX_BASICS][..self.basics];
+        let Some(cards) = self.transformer.transform(seed, basics) else {
+            unreachable!()
+        };
+        cards
     }
 
     pub fn constant_pandoras_box(&self, seed: &seed::Seed) -> Option<Card> {
-        let cards = self.pandoras_box(seed);
-        let first = cards[0];
-        let leftover = &cards[1..];
-        for other in leftover {
-            if first != *other {
-                return None;
-            }
-        }
-        Some(first)
+        self.pandoras_box(seed).constant()
     }
 }
 
+// only counts which draws agree, so it never needs to know the cards themselves
 pub struct AnonymousPandoraBox {
     cards: u64,
     basics: usize,
+    same: usize,
+    source: TransformSource,
 }
 
 impl AnonymousPandoraBox {
-    pub const fn new(_character: Character) -> Self {
-        // let basics: usize = basics(character);
-        // let range = card_pool_range(character, None);
-        todo!()
-        // let cards = range.1 as u64 - range.0 as u64 + 1;
-        // Self { cards, basics }
+    pub const fn new(character: Character) -> Self {
+        let basics = basics(character);
+        Self {
+            cards: TransformPool::new(character, None).len() as u64,
+            basics,
+            same: basics,
+            source: TransformSource::PandorasBox { floor: NEOW_FLOOR },
+        }
+    }
+
+    pub const fn with_floor(self, floor: usize) -> Self {
+        Self {
+            source: TransformSource::PandorasBox { floor },
+            ..self
+        }
+    }
+
+    // accepts when at least `same` of the transformed cards are one card; asking for more cards
+    // than the character has basics rejects every seed
+    pub const fn with_at_least(self, same: usize) -> Self {
+        Self { same, ..self }
     }
 }
 
-impl SeedFilter for AnonymousPandoraBox {
+impl RngFilter for AnonymousPandoraBox {
     fn reject_rng(&self, rng: &mut Random) -> bool {
-        let first = rng.next_capped_u64(self.cards);
-        for _ in 1..self.basics {
-            if first != rng.next_capped_u64(self.cards) {
-                return true;
+        if self.same > self.basics {
+            return true;
+        }
+        if self.same == self.basics {
+            let first = rng.next_capped_u64(self.cards);
+            for _ in 1..self.basics {
+                if first != rng.next_capped_u64(self.cards) {
+                    return true;
+                }
             }
+            return false;
         }
-        false
+        let mut draws = [0; MAX_BASICS];
+        for draw in &mut draws[..self.basics] {
+            *draw = rng.next_capped_u64(self.cards);
+        }
+        let draws = &draws[..self.basics];
+        !draws
+            .iter()
+            .any(|draw| draws.iter().filter(|other| *other == draw).count() >= self.same)
+    }
+
+    fn rng(&self, seed: &seed::Seed) -> Random {
+        self.source.rng(seed)
     }
 }
 
@@ -66,14 +123,74 @@ impl SeedFilter for AnonymousPandoraBox {
 mod tests {
     use std::dbg;
 
-    use crate::{card::pandoras_box::PandorasBox, seed};
+    use crate::{card::pandoras_box::PandorasBox, character::Character, seed};
 
     #[test]
     fn test_pandoras_box() {
         let seed = seed::Seed { seed: 1 };
-        const FILTER: PandorasBox = PandorasBox::new(&crate::card::CARDS);
+        const FILTER: PandorasBox = PandorasBox::new(Character::Silent, None);
         let cards = FILTER.pandoras_box(&seed);
         dbg!(cards);
         dbg!(FILTER.constant_pandoras_box(&seed));
+        assert_eq!(cards.as_slice().len(), 10);
+        assert!(cards.as_slice().iter().all(|card| !card.is_healing()));
+        let boss_chest = FILTER.with_floor(17).pandoras_box(&seed);
+        assert_ne!(boss_chest, cards);
+    }
+
+    #[test]
+    fn test_anonymous_pandoras_box() {
+        use crate::{card::pandoras_box::AnonymousPandoraBox, filter::SeedFilter};
+
+        const IRONCLAD: AnonymousPandoraBox = AnonymousPandoraBox::new(Character::Ironclad);
+        const BOX: PandorasBox = PandorasBox::new(Character::Ironclad, None);
+        const ANY: AnonymousPandoraBox = IRONCLAD.with_at_least(1);
+        const ALL: AnonymousPandoraBox = IRONCLAD.with_at_least(9);
+        const THREE: AnonymousPandoraBox = IRONCLAD.with_at_least(3);
+        let mut three = 0;
+        for seed in 1i64..2000 {
+            let seed = seed::Seed::from(seed);
+            let constant = BOX.constant_pandoras_box(&seed).is_some();
+            assert_eq!(IRONCLAD.reject(seed.clone()), !constant);
+            assert_eq!(ALL.reject(seed.clone()), !constant);
+            assert!(!ANY.reject(seed.clone()));
+
+            let cards = BOX.pandoras_box(&seed);
+            let cards = cards.as_slice();
+            let most = cards
+                .iter()
+                .map(|card| cards.iter().filter(|other| *other == card).count())
+                .max();
+            assert_eq!(THREE.reject(seed.clone()), most < Some(3));
+            three += usize::from(most >= Some(3));
+        }
+        assert!(three > 0);
+
+        let seed = seed::Seed::from(5i64);
+        let constant = BOX.with_floor(17).constant_pandoras_box(&seed).is_some();
+        assert_eq!(IRONCLAD.with_floor(17).reject(seed), !constant);
+    }
+
+    #[test]
+    fn test_more_than_basics() {
+        use crate::{
+            card::{pandoras_box::AnonymousPandoraBox, transform::MAX_BASICS},
+            filter::SeedFilter,
+        };
+
+        const TEN: AnonymousPandoraBox =
+            AnonymousPandoraBox::new(Character::Ironclad).with_at_least(10);
+        const TOO_MANY: AnonymousPandoraBox =
+            AnonymousPandoraBox::new(Character::Silent).with_at_least(MAX_BASICS + 1);
+        const SILENT: AnonymousPandoraBox =
+            AnonymousPandoraBox::new(Character::Silent).with_at_least(MAX_BASICS);
+        assert!((1i64..2000).all(|seed| TEN.reject(seed) && TOO_MANY.reject(seed)));
+        for seed in 1i64..200 {
+            let seed = seed::Seed::from(seed);
+            let constant = PandorasBox::new(Character::Silent, None)
+                .constant_pandoras_box(&seed)
+                .is_some();
+            assert_eq!(SILENT.reject(seed), !constant);
+        }
     }
 }
@@ -1,6 +1,8 @@
-use crate::character::Character;
+use strum::EnumCount;
 
-use super::{Card, CardSlice, Rarity, CARDS, REV_CARDS};
+use crate::{character::Character, unlock::UnlockLevel};
+
+use super::{Card, CardSlice, Color, Rarity, CARDS, REV_CARDS};
 
 pub struct CharacterCards<'a> {
     pub all: CardSlice<'a>,
@@ -22,6 +24,299 @@ impl<'a> CharacterCards<'a> {
     }
 }
 
+impl CharacterCards<'static> {
+    // the pools without the cards still locked at `level`, in the same order
+    pub const fn unlocked(character: Character, level: UnlockLevel) -> Self {
+        let pools = &UNLOCKED_CARDS[character as usize][level as usize];
+        Self {
+            all: pools.all.slice(),
+            common: pools.common.slice(),
+            uncommon: pools.uncommon.slice(),
+            rare: pools.rare.slice(),
+        }
+    }
+}
+
+// sorted by card id, the order `getRandomCard(true, rarity)` picks from
+pub struct ColorlessCards<'a> {
+    pub uncommon: CardSlice<'a>,
+    pub rare: CardSlice<'a>,
+}
+
+impl<'a> ColorlessCards<'a> {
+    pub const fn new() -> Self {
+        Self {
+            uncommon: CardSlice::new(&CARDS).trim_inclusive(Card::BandageUp, Card::Trip),
+            rare: CardSlice::new(&CARDS).trim_inclusive(Card::Apotheosis, Card::Violence),
+        }
+    }
+}
+
+impl Default for ColorlessCards<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> CharacterCards<'a> {
+    pub fn rarity(&self, rarity: Rarity) -> &'a [Card] {
+        match rarity {
+            Rarity::Common => self.common.slice,
+            Rarity::Uncommon => self.uncommon.slice,
+            Rarity::Rare => self.rare.slice,
+        }
+    }
+}
+
+impl CharacterCards<'static> {
+    // a daily whose modifier adds another color's cards; dailies treat every card as unlocked
+    pub const fn daily(character: Character, color: Color) -> Self {
+        if color as usize == Color::of(character) as usize {
+            return Self::new(character);
+        }
+        let pools = &DAILY_CARDS[character as usize][color as usize];
+        Self {
+            all: pools.all.slice(),
+            common: pools.common.slice(),
+            uncommon: pools.uncommon.slice(),
+            rare: pools.rare.slice(),
+        }
+    }
+}
+
+// every card of a rarity in the library, colorless included, sorted by card id the way
+// `getAnyColorCard` picks from them
+pub struct AnyColorCards<'a> {
+    pub common: CardSlice<'a>,
+    pub uncommon: CardSlice<'a>,
+    pub rare: CardSlice<'a>,
+}
+
+impl AnyColorCards<'static> {
+    pub const fn new() -> Self {
+        let [common, uncommon, rare] = &ANY_COLOR_CARDS;
+        Self {
+            common: common.slice(),
+            uncommon: uncommon.slice(),
+            rare: rare.slice(),
+        }
+    }
+}
+
+impl AnyColorCards<'_> {
+    pub fn card_pool(&self, rarity: Rarity) -> &[Card] {
+        match rarity {
+            Rarity::Common => self.common.slice,
+            Rarity::Uncommon => self.uncommon.slice,
+            Rarity::Rare => self.rare.slice,
+        }
+    }
+}
+
+impl Default for AnyColorCards<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Ironclad has the most cards
+const MAX_POOL_LEN: usize = 72;
+
+#[derive(Clone, Copy)]
+struct CardPool<const N: usize = MAX_POOL_LEN> {
+    cards: [Card; N],
+    len: usize,
+}
+
+impl<const N: usize> CardPool<N> {
+    const EMPTY: Self = Self {
+        cards: [Card::Invalid; N],
+        len: 0,
+    };
+
+    const fn extend(&mut self, cards: &[Card]) {
+        let mut i = 0;
+        while i < cards.len() {
+            self.cards[self.len] = cards[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+
+    // a stable insertion sort, comparing ids like `String.compareTo`
+    const fn sort_by_id(&mut self) {
+        let mut i = 1;
+        while i < self.len {
+            let mut j = i;
+            while j > 0 && id_less(self.cards[j], self.cards[j - 1]) {
+                let card = self.cards[j];
+                self.cards[j] = self.cards[j - 1];
+                self.cards[j - 1] = card;
+                j -= 1;
+            }
+            i += 1;
+        }
+    }
+
+    const fn unlocked(cards: &[Card], character: Character, level: UnlockLevel) -> Self {
+        let mut pool = Self::EMPTY;
+        let mut i = 0;
+        while i < cards.len() {
+            let card = cards[i];
+            if !level.is_locked(character, card) {
+                pool.cards[pool.len] = card;
+                pool.len += 1;
+            }
+            i += 1;
+        }
+        pool
+    }
+
+    const fn slice(&self) -> CardSlice<'_> {
+        CardSlice::new(self.cards.split_at(self.len).0)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct UnlockedCards {
+    all: CardPool,
+    common: CardPool,
+    uncommon: CardPool,
+    rare: CardPool,
+}
+
+static UNLOCKED_CARDS: [[UnlockedCards; UnlockLevel::COUNT]; Character::COUNT] = unlocked_cards();
+
+const fn unlocked_cards() -> [[UnlockedCards; UnlockLevel::COUNT]; Character::COUNT] {
+    let empty = UnlockedCards {
+        all: CardPool::EMPTY,
+        common: CardPool::EMPTY,
+        uncommon: CardPool::EMPTY,
+        rare: CardPool::EMPTY,
+    };
+    let mut pools = [[empty; UnlockLevel::COUNT]; Character::COUNT];
+    let mut i = 0;
+    while i < Character::COUNT {
+        let Some(character) = Character::from_repr(i) else {
+            unreachable!()
+        };
+        let cards = CharacterCards::new(character);
+        let mut j = 0;
+        while j < UnlockLevel::COUNT {
+            let Some(level) = UnlockLevel::from_repr(j) else {
+                unreachable!()
+            };
+            pools[i][j] = UnlockedCards {
+                all: CardPool::unlocked(cards.all.slice, character, level),
+                common: CardPool::unlocked(cards.common.slice, character, level),
+                uncommon: CardPool::unlocked(cards.uncommon.slice, character, level),
+                rare: CardPool::unlocked(cards.rare.slice, character, level),
+            };
+            j += 1;
+        }
+        i += 1;
+    }
+    pools
+}
+
+const fn id_less(a: Card, b: Card) -> bool {
+    let (a, b) = (a.id().as_bytes(), b.id().as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
+
+const MAX_ANY_COLOR_LEN: usize = 160;
+
+static ANY_COLOR_CARDS: [CardPool<MAX_ANY_COLOR_LEN>; 3] = any_color_cards();
+
+const fn any_color_cards() -> [CardPool<MAX_ANY_COLOR_LEN>; 3] {
+    let mut pools = [CardPool::EMPTY; 3];
+    let mut i = 0;
+    while i < Character::COUNT {
+        let Some(character) = Character::from_repr(i) else {
+            unreachable!()
+        };
+        let cards = CharacterCards::new(character);
+        pools[0].extend(cards.common.slice);
+        pools[1].extend(cards.uncommon.slice);
+        pools[2].extend(cards.rare.slice);
+        i += 1;
+    }
+    let colorless = ColorlessCards::new();
+    pools[1].extend(colorless.uncommon.slice);
+    pools[2].extend(colorless.rare.slice);
+    let mut rarity = 0;
+    while rarity < pools.len() {
+        pools[rarity].sort_by_id();
+        rarity += 1;
+    }
+    pools
+}
+
+#[derive(Clone, Copy)]
+struct DailyCards {
+    all: CardPool<{ 2 * MAX_POOL_LEN }>,
+    common: CardPool,
+    uncommon: CardPool,
+    rare: CardPool,
+}
+
+static DAILY_CARDS: [[DailyCards; Color::COUNT]; Character::COUNT] = daily_cards();
+
+// the added color comes after the character's own cards in the library walk, so it leads
+// each of the reversed rarity pools
+const fn daily_cards() -> [[DailyCards; Color::COUNT]; Character::COUNT] {
+    let empty = DailyCards {
+        all: CardPool::EMPTY,
+        common: CardPool::EMPTY,
+        uncommon: CardPool::EMPTY,
+        rare: CardPool::EMPTY,
+    };
+    let mut pools = [[empty; Color::COUNT]; Character::COUNT];
+    let mut i = 0;
+    while i < Character::COUNT {
+        let Some(character) = Character::from_repr(i) else {
+            unreachable!()
+        };
+        let own = CharacterCards::new(character);
+        let mut j = 0;
+        while j < Color::COUNT {
+            let Some(color) = Color::from_repr(j) else {
+                unreachable!()
+            };
+            let added = match color.character() {
+                Some(other) => CharacterCards::new(other),
+                None => CharacterCards {
+                    all: CardSlice::new(&CARDS).trim_inclusive(Card::BandageUp, Card::Violence),
+                    common: CardSlice::new(&[]),
+                    uncommon: CardSlice::new(&REV_CARDS)
+                        .trim_inclusive(Card::Trip, Card::BandageUp),
+                    rare: CardSlice::new(&REV_CARDS)
+                        .trim_inclusive(Card::Violence, Card::Apotheosis),
+                },
+            };
+            let pool = &mut pools[i][j];
+            pool.all.extend(own.all.slice);
+            pool.all.extend(added.all.slice);
+            pool.common.extend(added.common.slice);
+            pool.common.extend(own.common.slice);
+            pool.uncommon.extend(added.uncommon.slice);
+            pool.uncommon.extend(own.uncommon.slice);
+            pool.rare.extend(added.rare.slice);
+            pool.rare.extend(own.rare.slice);
+            j += 1;
+        }
+        i += 1;
+    }
+    pools
+}
+
 struct CardSliceParameters {
     first: Card,
     len: usize,
@@ -71,3 +366,53 @@ impl CardSliceParameters {
         }
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod card_pool_tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_any_color_cards() {
+        let any_color = AnyColorCards::new();
+        for rarity in Rarity::iter() {
+            let cards = any_color.card_pool(rarity);
+            assert!(cards.windows(2).all(|pair| pair[0].id() < pair[1].id()));
+            let colored: usize = Character::iter()
+                .map(|character| CharacterCards::new(character).rarity(rarity).len())
+                .sum();
+            let colorless = match rarity {
+                Rarity::Common => 0,
+                Rarity::Uncommon => ColorlessCards::new().uncommon.slice.len(),
+                Rarity::Rare => ColorlessCards::new().rare.slice.len(),
+            };
+            assert_eq!(cards.len(), colored + colorless, "{rarity:?}");
+        }
+    }
+
+    #[test]
+    fn test_daily_cards() {
+        for character in Character::iter() {
+            let own = CharacterCards::new(character);
+            for color in Color::iter() {
+                let daily = CharacterCards::daily(character, color);
+                if color == Color::of(character) {
+                    assert_eq!(daily.all.slice, own.all.slice);
+                    continue;
+                }
+                for rarity in Rarity::iter() {
+                    let pool = daily.rarity(rarity);
+                    let (added, rest) = pool.split_at(pool.len() - own.rarity(rarity).len());
+                    assert_eq!(rest, own.rarity(rarity), "{character:?} {color:?}");
+                    assert!(added.iter().all(|card| card.color() == color));
+                }
+                assert_eq!(&daily.all.slice[..own.all.slice.len()], own.all.slice);
+            }
+            let colorless = CharacterCards::daily(character, Color::Colorless);
+            assert_eq!(colorless.common.slice, own.common.slice);
+            assert!(colorless.rare.slice.contains(&Card::Apotheosis));
+        }
+    }
+}
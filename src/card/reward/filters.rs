@@ -1,12 +1,15 @@
 use libgdx_xs128::rng::Random;
 
-use crate::{filter::SeedFilter, unlock::Unlocks, card::Card, character::Character};
+use crate::{
+    card::Card, character::Character, filter::RngFilter, rng::Stream, seed::Seed, unlock::Unlocks,
+};
 
-use super::CardRewarder;
+use super::{CardRewarder, RewardContext};
 
 pub struct CardRewardFilter<'a, const REWARDS: usize> {
     reward: CardRewarder<'a, REWARDS>,
     rejected_cards: &'a [Card],
+    contexts: [RewardContext; REWARDS],
 }
 
 impl<'a, const N: usize> CardRewardFilter<'a, N> {
@@ -18,16 +21,28 @@ impl<'a, const N: usize> CardRewardFilter<'a, N> {
         Self {
             reward: CardRewarder::new(character, unlocks),
             rejected_cards,
+            contexts: [RewardContext::Normal; N],
         }
     }
+
+    // the fights along the route, e.g. an elite as the third reward
+    pub const fn with_contexts(self, contexts: [RewardContext; N]) -> Self {
+        Self { contexts, ..self }
+    }
 }
 
-impl<'a, const REWARDS: usize> SeedFilter for CardRewardFilter<'a, REWARDS> {
+impl<'a, const REWARDS: usize> RngFilter for CardRewardFilter<'a, REWARDS> {
     fn reject_rng(&self, rng: &mut Random) -> bool {
-        let rewards = self.reward.generate_rewards(rng);
-        rewards
-            .iter()
-            .any(|reward| reward.iter().any(|card| self.rejected_cards.contains(card)))
+        let rewards = self.reward.generate_rewards_in(rng, self.contexts);
+        rewards.iter().any(|reward| {
+            reward
+                .iter()
+                .any(|reward| self.rejected_cards.contains(&reward.card))
+        })
+    }
+
+    fn rng(&self, seed: &Seed) -> Random {
+        Stream::Card.rng(seed)
     }
 }
 
@@ -41,8 +56,8 @@ mod card_reward_tests {
 
     use crate::{
         card::{
-            reward::{CardRewarder, filters::CardRewardFilter},
-            Card,
+            reward::{filters::CardRewardFilter, CardReward, CardRewarder},
+            Card, Rarity,
         },
         character::Character,
         filter::SeedFilter,
@@ -61,8 +76,9 @@ mod card_reward_tests {
         let rewards = rewarder.generate_rewards(&mut rng);
 
         dbg!(&rewards);
+        assert!(rewards.iter().flatten().all(|reward| !reward.upgraded));
         assert_eq!(
-            rewards,
+            rewards.map(|reward| reward.map(|reward| reward.card)),
             [
                 [Card::Prepared, Card::DodgeAndRoll, Card::EscapePlan],
                 [Card::EscapePlan, Card::Outmaneuver, Card::Prepared],
@@ -83,8 +99,9 @@ mod card_reward_tests {
         let rewards = REWARDER.generate_rewards(&mut rng);
 
         dbg!(&rewards);
+        assert!(rewards.iter().flatten().all(|reward| !reward.upgraded));
         assert_eq!(
-            rewards,
+            rewards.map(|reward| reward.map(|reward| reward.card)),
             [
                 [Card::Prepared, Card::DodgeAndRoll, Card::EscapePlan],
                 [Card::EscapePlan, Card::Outmaneuver, Card::Prepared],
@@ -92,4 +109,162 @@ mod card_reward_tests {
             ]
         )
     }
+
+    #[test]
+    fn test_unlocked_card_pools() {
+        use crate::unlock::{UnlockLevel, Unlocks};
+
+        const FULL: CardRewarder<'_, 3> =
+            CardRewarder::new(Character::Silent, Some(Unlocks::full()));
+        const FRESH: CardRewarder<'_, 3> = CardRewarder::new(
+            Character::Silent,
+            Some(Unlocks::new([UnlockLevel::Zero; 4])),
+        );
+        const ALL: CardRewarder<'_, 3> = CardRewarder::new(Character::Silent, None);
+        for rarity in [Rarity::Common, Rarity::Uncommon, Rarity::Rare] {
+            assert_eq!(FULL.card_pool(rarity), ALL.card_pool(rarity));
+            let unlocked = ALL
+                .card_pool(rarity)
+                .iter()
+                .copied()
+                .filter(|&card| !UnlockLevel::Zero.is_locked(Character::Silent, card));
+            assert!(FRESH.card_pool(rarity).iter().copied().eq(unlocked));
+        }
+        assert_eq!(FRESH.card_pool(Rarity::Common).len(), 17);
+        assert_eq!(FRESH.card_pool(Rarity::Uncommon).len(), 29);
+        assert_eq!(FRESH.card_pool(Rarity::Rare).len(), 16);
+
+        for seed in 1u64..100 {
+            let rewards = FRESH.generate_rewards(&mut Random::new(seed));
+            assert!(rewards
+                .iter()
+                .flatten()
+                .all(|reward| !UnlockLevel::Zero.is_locked(Character::Silent, reward.card)));
+            assert_eq!(
+                FULL.generate_rewards(&mut Random::new(seed)),
+                ALL.generate_rewards(&mut Random::new(seed))
+            );
+        }
+    }
+
+    #[test]
+    fn test_reward_contexts() {
+        use crate::card::reward::RewardContext::*;
+
+        const REWARDER: CardRewarder<'_, 3> = CardRewarder::new(Character::Ironclad, None);
+        let (mut normal, mut elite) = (0, 0);
+        for seed in 1u64..500 {
+            let rewards =
+                REWARDER.generate_rewards_in(&mut Random::new(seed), [Normal, Boss, Elite]);
+            assert_eq!(
+                rewards[0],
+                REWARDER.generate_rewards(&mut Random::new(seed))[0]
+            );
+            assert!(rewards[1]
+                .iter()
+                .all(|reward| REWARDER.card_pool(Rarity::Rare).contains(&reward.card)));
+            let rares = |reward: &CardReward| {
+                reward
+                    .iter()
+                    .filter(|reward| REWARDER.card_pool(Rarity::Rare).contains(&reward.card))
+                    .count()
+            };
+            normal += rares(&rewards[0]);
+            elite += rares(&rewards[2]);
+        }
+        assert!(elite > normal, "{elite} {normal}");
+
+        let filter: CardRewardFilter<'_, 1> =
+            CardRewardFilter::new(Character::Ironclad, None, REWARDER.card_pool(Rarity::Rare))
+                .with_contexts([Boss]);
+        assert!((1u64..50).all(|seed| filter.reject(seed)));
+    }
+
+    #[test]
+    fn test_upgrades() {
+        use crate::{card::Kind, map::Act, relic::Relic};
+
+        const ACT_ONE: CardRewarder<'_, 3> = CardRewarder::new(Character::Defect, None);
+        const ACT_THREE: CardRewarder<'_, 3> = ACT_ONE.in_act(Act::Three, 0);
+        const ELEVEN: CardRewarder<'_, 3> = ACT_ONE.in_act(Act::Three, 11);
+        const ASCENDED: CardRewarder<'_, 3> = ACT_ONE.in_act(Act::Three, 12);
+        const EGGS: CardRewarder<'_, 3> = ACT_ONE.with_eggs(&[Relic::MoltenEgg]);
+        let mut upgraded = 0;
+        let mut ascended = 0;
+        for seed in 1u64..200 {
+            let first = ACT_ONE.generate_rewards(&mut Random::new(seed));
+            let third = ACT_THREE.generate_rewards(&mut Random::new(seed));
+            // act I rolls the same calls with no chance of success
+            assert!(first.iter().flatten().all(|reward| !reward.upgraded));
+            assert_eq!(
+                first[0].map(|reward| reward.card),
+                third[0].map(|reward| reward.card)
+            );
+            for reward in third.iter().flatten().filter(|reward| reward.upgraded) {
+                assert!(!ACT_ONE.card_pool(Rarity::Rare).contains(&reward.card));
+                upgraded += 1;
+            }
+            assert_eq!(ELEVEN.generate_rewards(&mut Random::new(seed)), third);
+            let halved = ASCENDED.generate_rewards(&mut Random::new(seed));
+            ascended += halved
+                .iter()
+                .flatten()
+                .filter(|reward| reward.upgraded)
+                .count();
+            let eggs = EGGS.generate_rewards(&mut Random::new(seed));
+            for (egg, plain) in eggs.iter().flatten().zip(first.iter().flatten()) {
+                assert_eq!(egg.card, plain.card);
+                assert_eq!(egg.upgraded, egg.card.kind() == Some(Kind::Attack));
+            }
+        }
+        assert!(upgraded > ascended && ascended > 0, "{upgraded} {ascended}");
+    }
+
+    #[test]
+    fn test_prismatic_shard() {
+        use strum::IntoEnumIterator;
+
+        use crate::{
+            card::{pool::AnyColorCards, Color},
+            unlock::{UnlockLevel, Unlocks},
+        };
+
+        const SHARD: CardRewarder<'_, 3> =
+            CardRewarder::new(Character::Watcher, None).with_prismatic_shard();
+        let any_color = AnyColorCards::new();
+        let mut foreign = 0;
+        for seed in 1u64..100 {
+            for reward in SHARD
+                .generate_rewards(&mut Random::new(seed))
+                .iter()
+                .flatten()
+            {
+                assert!(
+                    Rarity::iter().any(|rarity| any_color.card_pool(rarity).contains(&reward.card))
+                );
+                if reward.card.color() != Color::Purple {
+                    foreign += 1;
+                }
+            }
+        }
+        assert!(foreign > 0);
+
+        // a fresh profile cannot be offered another character's locked cards
+        let fresh: CardRewarder<'_, 3> = CardRewarder::new(
+            Character::Watcher,
+            Some(Unlocks::new([UnlockLevel::Zero; 4])),
+        )
+        .with_prismatic_shard();
+        for seed in 1u64..100 {
+            for reward in fresh
+                .generate_rewards(&mut Random::new(seed))
+                .iter()
+                .flatten()
+            {
+                if let Some(character) = reward.card.color().character() {
+                    assert!(!UnlockLevel::Zero.is_locked(character, reward.card));
+                }
+            }
+        }
+    }
 }
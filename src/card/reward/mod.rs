@@ -1,10 +1,13 @@
-use core::array;
+use core::{array, fmt};
 
 use libgdx_xs128::{rng::Random, RandomXS128};
 
-use crate::{character::Character, unlock::Unlocks};
+use crate::{character::Character, map::Act, relic::Relic, rng::random_boolean, unlock::Unlocks};
 
-use super::{pool::CharacterCards, Card, Rarity};
+use super::{
+    pool::{AnyColorCards, CharacterCards},
+    Card, Color, Kind, Rarity,
+};
 
 // #[cfg(feature = "std")]
 pub mod filters;
@@ -13,11 +16,56 @@ pub struct CardRewarder<'a, const REWARDS: usize> {
     common: &'a [Card],
     uncommon: &'a [Card],
     rare: &'a [Card],
+    upgrade_chance: f32,
+    eggs: &'a [Relic],
+    unlocks: Option<Unlocks>,
+    prismatic_shard: bool,
 }
 
 impl<'a, const REWARDS: usize> CardRewarder<'a, REWARDS> {}
 
-pub type CardReward = [Card; 3];
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct RewardCard {
+    pub card: Card,
+    pub upgraded: bool,
+}
+
+impl RewardCard {
+    pub const fn new(card: Card) -> Self {
+        Self {
+            card,
+            upgraded: false,
+        }
+    }
+}
+
+impl fmt::Display for RewardCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}{}", self.card, if self.upgraded { "+" } else { "" })
+    }
+}
+
+pub type CardReward = [RewardCard; 3];
+
+// `cardUpgradedChance` by act, halved from ascension 12
+const fn upgrade_chance(act: Act, ascension: u8) -> f32 {
+    match (act, ascension >= 12) {
+        (Act::One, _) => 0.0,
+        (Act::Two, false) => 0.25,
+        (Act::Two, true) => 0.125,
+        (Act::Three | Act::Four, false) => 0.5,
+        (Act::Three | Act::Four, true) => 0.25,
+    }
+}
+
+// the egg that upgrades every card of a kind as it is offered
+const fn egg(kind: Kind) -> Relic {
+    match kind {
+        Kind::Attack => Relic::MoltenEgg,
+        Kind::Skill => Relic::ToxicEgg,
+        Kind::Power => Relic::FrozenEgg,
+    }
+}
 
 #[derive(Debug)]
 pub struct Offset {
@@ -27,6 +75,25 @@ pub struct Offset {
 const DEFAULT_OFFSET: i64 = -5;
 const UNCOMMON_CUTOFF: i64 = 37;
 const RARE_CUTOFF: i64 = 3;
+const ELITE_UNCOMMON_CUTOFF: i64 = 40;
+const ELITE_RARE_CUTOFF: i64 = 10;
+const SHOP_RARE_CUTOFF: i64 = 9;
+// Neow rolls its own rarities, with no rares unless the option offers only rares
+const EVENT_UNCOMMON_CHANCE: f32 = 0.33;
+
+// where a reward comes from, which decides its rarity chances
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum RewardContext {
+    #[default]
+    Normal,
+    Elite,
+    // all rares, though the roll is still made and resets the offset
+    Boss,
+    // Neow's card choices, which neither read nor move the offset
+    Event {
+        rare_only: bool,
+    },
+}
 
 impl Offset {
     fn reset(&mut self) {
@@ -41,17 +108,54 @@ impl Offset {
         rng.next_capped_u64(100) as i64 + self.offset
     }
 
-    fn generate_rarity(&mut self, rng: &mut Random) -> Rarity {
-        let adjusted_percentage = self.adjusted_percentage(rng);
-        if adjusted_percentage < RARE_CUTOFF {
-            self.reset();
-            Rarity::Rare
-        } else if adjusted_percentage <= UNCOMMON_CUTOFF {
-            Rarity::Uncommon
-        } else {
-            self.decrement();
-            Rarity::Common
+    fn generate_rarity(&mut self, rng: &mut Random, context: RewardContext) -> Rarity {
+        let rarity = match context {
+            RewardContext::Normal => {
+                rarity(self.adjusted_percentage(rng), RARE_CUTOFF, UNCOMMON_CUTOFF)
+            }
+            RewardContext::Elite => rarity(
+                self.adjusted_percentage(rng),
+                ELITE_RARE_CUTOFF,
+                ELITE_UNCOMMON_CUTOFF,
+            ),
+            RewardContext::Boss => {
+                self.adjusted_percentage(rng);
+                Rarity::Rare
+            }
+            RewardContext::Event { rare_only } => {
+                let uncommon = random_boolean(rng, EVENT_UNCOMMON_CHANCE);
+                return match (rare_only, uncommon) {
+                    (true, _) => Rarity::Rare,
+                    (false, true) => Rarity::Uncommon,
+                    (false, false) => Rarity::Common,
+                };
+            }
+        };
+        match rarity {
+            Rarity::Rare => self.reset(),
+            Rarity::Uncommon => {}
+            Rarity::Common => self.decrement(),
         }
+        rarity
+    }
+
+    // the merchant's `rollRarity` reads the offset but never moves it
+    pub fn shop_rarity(&self, rng: &mut Random) -> Rarity {
+        rarity(
+            self.adjusted_percentage(rng),
+            SHOP_RARE_CUTOFF,
+            UNCOMMON_CUTOFF,
+        )
+    }
+}
+
+fn rarity(adjusted_percentage: i64, rare_cutoff: i64, uncommon_cutoff: i64) -> Rarity {
+    if adjusted_percentage < rare_cutoff {
+        Rarity::Rare
+    } else if adjusted_percentage <= uncommon_cutoff {
+        Rarity::Uncommon
+    } else {
+        Rarity::Common
     }
 }
 
@@ -65,47 +169,131 @@ impl Default for Offset {
 
 impl<'a, const REWARDS: usize> CardRewarder<'a, REWARDS> {
     pub const fn new(character: Character, unlocks: Option<Unlocks>) -> Self {
-        if let Some(_unlocks) = unlocks {
-            unimplemented!()
-        } else {
-            let CharacterCards {
-                all: _,
-                common,
-                uncommon,
-                rare,
-            } = CharacterCards::new(character);
-            Self {
-                common: common.slice,
-                uncommon: uncommon.slice,
-                rare: rare.slice,
-            }
+        let cards = match unlocks {
+            Some(unlocks) => CharacterCards::unlocked(character, unlocks.level(character)),
+            None => CharacterCards::new(character),
+        };
+        Self::from_cards(cards, unlocks)
+    }
+
+    // the pools of a daily whose modifier adds `color`
+    pub const fn daily(character: Character, color: Color) -> Self {
+        Self::from_cards(CharacterCards::daily(character, color), None)
+    }
+
+    const fn from_cards(cards: CharacterCards<'static>, unlocks: Option<Unlocks>) -> Self {
+        let CharacterCards {
+            all: _,
+            common,
+            uncommon,
+            rare,
+        } = cards;
+        Self {
+            common: common.slice,
+            uncommon: uncommon.slice,
+            rare: rare.slice,
+            upgrade_chance: upgrade_chance(Act::One, 0),
+            eggs: &[],
+            unlocks,
+            prismatic_shard: false,
+        }
+    }
+
+    // rewards drawn from every color, colorless included
+    pub const fn with_prismatic_shard(self) -> Self {
+        Self {
+            prismatic_shard: true,
+            ..self
         }
     }
 
+    // the act the rewards are drawn in, at an ascension level; from 12 upgrades are halved
+    pub const fn in_act(self, act: Act, ascension: u8) -> Self {
+        Self {
+            upgrade_chance: upgrade_chance(act, ascension),
+            ..self
+        }
+    }
+
+    // the eggs among the player's relics
+    pub const fn with_eggs(self, eggs: &'a [Relic]) -> Self {
+        Self { eggs, ..self }
+    }
+
     pub fn generate_rewards(&self, rng: &mut Random) -> [CardReward; REWARDS] {
+        self.generate_rewards_in(rng, [RewardContext::Normal; REWARDS])
+    }
+
+    // the first `REWARDS` rewards of a route, one context per fight
+    pub fn generate_rewards_in(
+        &self,
+        rng: &mut Random,
+        contexts: [RewardContext; REWARDS],
+    ) -> [CardReward; REWARDS] {
         let mut offset = Offset::default();
-        array::from_fn(|_| self.generate_reward(rng, &mut offset))
+        array::from_fn(|i| self.generate_reward(rng, &mut offset, contexts[i]))
     }
 
-    pub fn generate_reward(&self, rng: &mut Random, offset: &mut Offset) -> CardReward {
-        let mut reward: [Card; 3] = Default::default();
+    pub fn generate_reward(
+        &self,
+        rng: &mut Random,
+        offset: &mut Offset,
+        context: RewardContext,
+    ) -> CardReward {
+        let mut cards: [Card; 3] = Default::default();
+        let mut rarities = [Rarity::Common; 3];
         for i in 0..3 {
-            let rarity = offset.generate_rarity(rng);
+            let rarity = offset.generate_rarity(rng, context);
             let mut card = self.generate_card(rng, rarity);
-            while reward.contains(&card) {
+            while cards.contains(&card) {
                 card = self.generate_card(rng, rarity);
             }
-            reward[i] = card;
+            cards[i] = card;
+            rarities[i] = rarity;
         }
-        rng.advance(3);
-        reward
+        array::from_fn(|i| {
+            let card = cards[i];
+            // Neow rolls no upgrades, and rares skip the roll
+            let rolled = !matches!(context, RewardContext::Event { .. })
+                && rarities[i] != Rarity::Rare
+                && random_boolean(rng, self.upgrade_chance);
+            let hatched = card
+                .kind()
+                .is_some_and(|kind| self.eggs.contains(&egg(kind)));
+            RewardCard {
+                card,
+                upgraded: rolled || hatched,
+            }
+        })
     }
 
     pub fn generate_card(&self, rng: &mut Random, rarity: Rarity) -> Card {
+        if self.prismatic_shard {
+            return self.any_color_card(rng, rarity);
+        }
         let cards = self.card_pool(rarity);
         cards[rng.next_capped_u64(cards.len() as u64) as usize]
     }
 
+    // `getAnyColorCard` shuffles before sorting by id, so the shuffle only costs a draw
+    fn any_color_card(&self, rng: &mut Random, rarity: Rarity) -> Card {
+        rng.next_u64();
+        let unlocked = |card: &&Card| match (self.unlocks, card.color().character()) {
+            (Some(unlocks), Some(character)) => {
+                !unlocks.level(character).is_locked(character, **card)
+            }
+            _ => true,
+        };
+        let any_color = AnyColorCards::new();
+        let cards = any_color.card_pool(rarity);
+        let len = cards.iter().filter(unlocked).count();
+        let index = rng.next_capped_u64(len as u64) as usize;
+        let Some(&card) = cards.iter().filter(unlocked).nth(index) else {
+            unreachable!()
+        };
+        card
+    }
+
     pub fn card_pool(&self, rarity: Rarity) -> &[Card] {
         match rarity {
             Rarity::Common => self.common,
@@ -0,0 +1,281 @@
+use libgdx_xs128::{rng::Random, RandomXS128};
+
+use crate::{
+    character::Character, neow::contents::OPTION_ROLLS, rng::Stream, seed::Seed, unlock::Unlocks,
+};
+
+use super::{pool::CharacterCards, Card};
+
+// Ironclad has the most cards
+const MAX_TRANSFORM_LEN: usize = 72;
+
+// the Silent starts with the most strikes and defends
+pub const MAX_BASICS: usize = 10;
+
+// what a colored card can become: the character's commons, uncommons and rares in pool
+// order, without the healing cards
+#[derive(Clone, Copy)]
+pub struct TransformPool {
+    cards: [Card; MAX_TRANSFORM_LEN],
+    len: usize,
+}
+
+impl TransformPool {
+    pub const fn new(character: Character, unlocks: Option<Unlocks>) -> Self {
+        let cards = match unlocks {
+            Some(unlocks) => CharacterCards::unlocked(character, unlocks.level(character)),
+            None => CharacterCards::new(character),
+        };
+        let mut pool = Self {
+            cards: [Card::Invalid; MAX_TRANSFORM_LEN],
+            len: 0,
+        };
+        pool.extend(cards.common.slice);
+        pool.extend(cards.uncommon.slice);
+        pool.extend(cards.rare.slice);
+        pool
+    }
+
+    const fn extend(&mut self, cards: &[Card]) {
+        let mut i = 0;
+        while i < cards.len() {
+            if !cards[i].is_healing() {
+                self.cards[self.len] = cards[i];
+                self.len += 1;
+            }
+            i += 1;
+        }
+    }
+
+    pub fn as_slice(&self) -> &[Card] {
+        &self.cards[..self.len]
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // `returnTrulyRandomCardFromAvailable`, which never turns a card into itself; `None` when
+    // the pool has no other card to offer
+    pub fn transform(&self, rng: &mut Random, card: Card) -> Option<Card> {
+        let cards = self.as_slice();
+        let len = cards.len() - usize::from(cards.contains(&card));
+        if len == 0 {
+            return None;
+        }
+        let index = rng.next_capped_u64(len as u64) as usize;
+        cards
+            .iter()
+            .filter(|&&other| other != card)
+            .nth(index)
+            .copied()
+    }
+
+    // `returnTrulyRandomCard`, which Pandora's Box draws from
+    pub fn random_card(&self, rng: &mut Random) -> Card {
+        self.cards[rng.next_capped_u64(self.len as u64) as usize]
+    }
+}
+
+// what transforms cards, which decides the stream it draws from
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TransformSource {
+    // taken on `floor`: 0 from Neow's boss swap, 17 from the Act I boss chest
+    PandorasBox { floor: usize },
+    // `TransformCard` and `TransformTwoCards`, continuing the stream the options came from
+    Neow,
+    // upgrades the three cards it transforms
+    Astrolabe { floor: usize },
+}
+
+impl TransformSource {
+    pub fn rng(&self, seed: &Seed) -> Random {
+        let rng = match self {
+            TransformSource::PandorasBox { floor } => Stream::CardRandom.floor_rng(seed, *floor),
+            TransformSource::Astrolabe { floor } => Stream::Misc.floor_rng(seed, *floor),
+            TransformSource::Neow => {
+                let mut rng = Stream::Neow.rng(seed);
+                rng.advance(OPTION_ROLLS);
+                Some(rng)
+            }
+        };
+        let Some(rng) = rng else { unreachable!() };
+        rng
+    }
+
+    pub const fn upgrades(&self) -> bool {
+        matches!(self, TransformSource::Astrolabe { .. })
+    }
+}
+
+// cards drawn by one transform, at most a full set of basics
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TransformedCards {
+    cards: [Card; MAX_BASICS],
+    len: usize,
+    pub upgraded: bool,
+}
+
+impl TransformedCards {
+    pub fn as_slice(&self) -> &[Card] {
+        &self.cards[..self.len]
+    }
+
+    // the card every draw agreed on, if any
+    pub fn constant(&self) -> Option<Card> {
+        let (&first, rest) = self.as_slice().split_first()?;
+        rest.iter().all(|&card| card == first).then_some(first)
+    }
+}
+
+pub struct Transformer {
+    pool: TransformPool,
+    source: TransformSource,
+}
+
+impl Transformer {
+    pub const fn new(
+        character: Character,
+        unlocks: Option<Unlocks>,
+        source: TransformSource,
+    ) -> Self {
+        Self {
+            pool: TransformPool::new(character, unlocks),
+            source,
+        }
+    }
+
+    pub const fn with_source(self, source: TransformSource) -> Self {
+        Self { source, ..self }
+    }
+
+    pub const fn pool(&self) -> &TransformPool {
+        &self.pool
+    }
+
+    pub const fn source(&self) -> TransformSource {
+        self.source
+    }
+
+    // the cards `cards` become, in the order they are transformed; the basics are not in the
+    // pool, so they can be passed as `Card::Invalid`. `None` for more than `MAX_BASICS` cards
+    pub fn transform(&self, seed: &Seed, cards: &[Card]) -> Option<TransformedCards> {
+        if cards.len() > MAX_BASICS {
+            return None;
+        }
+        let mut rng = self.source.rng(seed);
+        let mut transformed = TransformedCards {
+            cards: [Card::Invalid; MAX_BASICS],
+            len: cards.len(),
+            upgraded: self.source.upgrades(),
+        };
+        for (slot, &card) in transformed.cards.iter_mut().zip(cards) {
+            *slot = match self.source {
+                TransformSource::PandorasBox { .. } => self.pool.random_card(&mut rng),
+                _ => self.pool.transform(&mut rng, card)?,
+            };
+        }
+        Some(transformed)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod transform_tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::card::Rarity;
+
+    #[test]
+    fn test_pools() {
+        for character in Character::iter() {
+            let pool = TransformPool::new(character, None);
+            let cards = CharacterCards::new(character);
+            let healing = cards
+                .all
+                .slice
+                .iter()
+                .filter(|card| card.is_healing())
+                .count();
+            assert_eq!(pool.len(), cards.all.slice.len() - healing, "{character:?}");
+            assert_eq!(
+                &pool.as_slice()[..cards.common.slice.len()],
+                cards.rarity(Rarity::Common)
+            );
+            assert!(!pool.as_slice().contains(&Card::Feed));
+        }
+        assert_eq!(crate::character::basics(Character::Silent), MAX_BASICS);
+    }
+
+    #[test]
+    fn test_transform_excludes_card() {
+        let pool = TransformPool::new(Character::Ironclad, None);
+        let first = pool.as_slice()[0];
+        for seed in 0u64..500 {
+            assert_ne!(pool.transform(&mut Random::new(seed), first), Some(first));
+        }
+
+        let mut single = pool;
+        single.len = 1;
+        assert_eq!(single.transform(&mut Random::new(0), first), None);
+        assert_eq!(
+            single.transform(&mut Random::new(0), Card::Invalid),
+            Some(first)
+        );
+    }
+
+    #[test]
+    fn test_too_many_cards() {
+        let seed = Seed::from(42i64);
+        let transformer = Transformer::new(Character::Silent, None, TransformSource::Neow);
+        let basics = [Card::Invalid; MAX_BASICS + 1];
+        assert!(transformer
+            .transform(&seed, &basics[..MAX_BASICS])
+            .is_some());
+        assert_eq!(transformer.transform(&seed, &basics), None);
+    }
+
+    #[test]
+    fn test_sources() {
+        let seed = Seed::from(42i64);
+        let basics = [Card::Invalid; 4];
+        let neow = Transformer::new(Character::Defect, None, TransformSource::Neow);
+        let mut rng = Stream::Neow.rng(&seed);
+        rng.advance(OPTION_ROLLS);
+        let expected = neow.pool().transform(&mut rng, Card::Invalid).unwrap();
+        assert_eq!(
+            neow.transform(&seed, &basics[..2]).unwrap().as_slice()[0],
+            expected
+        );
+
+        let boss_chest = TransformSource::PandorasBox { floor: 17 };
+        let pandora = Transformer::new(Character::Defect, None, boss_chest);
+        let mut rng = Stream::CardRandom.floor_rng(&seed, 17).unwrap();
+        let expected = pandora.pool().random_card(&mut rng);
+        assert_eq!(
+            pandora.transform(&seed, &basics).unwrap().as_slice()[0],
+            expected
+        );
+        assert_eq!(
+            pandora.transform(&seed, &basics).unwrap().as_slice().len(),
+            4
+        );
+
+        let astrolabe = Transformer::new(
+            Character::Defect,
+            None,
+            TransformSource::Astrolabe { floor: 17 },
+        );
+        let mut rng = Stream::Misc.floor_rng(&seed, 17).unwrap();
+        let expected = astrolabe.pool().transform(&mut rng, Card::Invalid).unwrap();
+        let cards = astrolabe.transform(&seed, &basics[..3]).unwrap();
+        assert_eq!(cards.as_slice()[0], expected);
+        assert!(cards.upgraded);
+        assert!(!pandora.transform(&seed, &basics).unwrap().upgraded);
+    }
+}
@@ -5,6 +5,7 @@ use strum_macros::FromRepr;
 #[cfg(feature = "std")]
 pub mod try_from;
 
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr, EnumIter, EnumCount)]
 pub enum Character {
     Ironclad,
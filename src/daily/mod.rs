@@ -1,8 +1,9 @@
 use alloc::string::String;
 
 use crate::{
+    card::reward::CardRewarder,
     character::Character,
-    neow::NeowBonuses,
+    neow::{Blessing, NeowBonuses, NeowOptions, MINI_BLESSING},
     seed::{Seed, SeedString},
 };
 
@@ -23,6 +24,23 @@ pub struct Daily {
     pub no_combat_paths: Option<String>,
     pub no_combat_paths_ascension: Option<String>,
     pub neow_bonuses: NeowBonuses,
+    pub blessing: Blessing,
+}
+
+impl Daily {
+    pub fn neow_options(&self) -> NeowOptions {
+        match self.blessing {
+            Blessing::Full => NeowOptions::Full(self.neow_bonuses),
+            Blessing::Mini => NeowOptions::Mini(MINI_BLESSING),
+        }
+    }
+
+    pub fn card_rewarder<const REWARDS: usize>(&self) -> CardRewarder<'static, REWARDS> {
+        match self.generic_mod.color() {
+            Some(color) => CardRewarder::daily(self.character, color),
+            None => CardRewarder::new(self.character, None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -190,6 +208,37 @@ mod test_daily_parser {
         }
     }
 
+    #[test]
+    fn test_parse_neow_options() {
+        for &daily_string in FIRST_DAILIES.iter() {
+            let daily: Daily = daily_string.parse().unwrap();
+            assert_eq!(daily.blessing, Blessing::Full);
+            assert_eq!(daily.neow_options(), NeowOptions::Full(daily.neow_bonuses));
+        }
+    }
+
+    #[test]
+    fn test_parse_mini_blessing() {
+        use alloc::format;
+
+        for &daily_string in FIRST_DAILIES.iter() {
+            let full: Daily = daily_string.parse().unwrap();
+            let seed = full.seed.seed;
+            let daily: Daily = format!("{daily_string},{seed},,,Mini").parse().unwrap();
+            assert_eq!(daily.blessing, Blessing::Mini);
+            assert_eq!(daily.neow_options(), NeowOptions::Mini(MINI_BLESSING));
+            assert_eq!(daily.neow_bonuses, full.neow_bonuses);
+
+            let daily: Daily = format!("{daily_string},{seed},,,Full").parse().unwrap();
+            assert_eq!(daily.neow_options(), full.neow_options());
+        }
+        let bad = format!("{},1352185017444331709,,,Half", FIRST_DAILIES[0]);
+        assert!(matches!(
+            bad.parse::<Daily>(),
+            Err(parse::Error::ParseBlessing(blessing)) if blessing == "Half"
+        ));
+    }
+
     #[test]
     fn test_parse_seed() {
         for (&true_seed, &daily_string) in FIRST_SEEDS.iter().zip(FIRST_DAILIES.iter()) {
@@ -197,4 +246,22 @@ mod test_daily_parser {
             assert_eq!(true_seed, daily.seed.seed);
         }
     }
+
+    #[test]
+    fn test_card_rewarder() {
+        use crate::card::{Color, Rarity};
+
+        let daily: Daily = FIRST_DAILIES[3].parse().unwrap();
+        let rewarder: CardRewarder<'_, 1> = daily.card_rewarder();
+        assert!(rewarder
+            .card_pool(Rarity::Uncommon)
+            .iter()
+            .any(|card| card.color() == Color::Colorless));
+        let daily: Daily = FIRST_DAILIES[1].parse().unwrap();
+        let rewarder: CardRewarder<'_, 1> = daily.card_rewarder();
+        assert!(rewarder
+            .card_pool(Rarity::Rare)
+            .iter()
+            .all(|card| card.color() == Color::Red));
+    }
 }
@@ -2,6 +2,8 @@ use std::str::FromStr;
 
 use alloc::string::String;
 
+use crate::card::Color;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum StarterMod {
     Insanity,
@@ -26,6 +28,20 @@ pub enum GenericMod {
     ControlledChaos,
 }
 
+impl GenericMod {
+    // the color whose cards the modifier adds to the character's pools
+    pub const fn color(&self) -> Option<Color> {
+        match self {
+            Self::RedCards => Some(Color::Red),
+            Self::GreenCards => Some(Color::Green),
+            Self::BlueCards => Some(Color::Blue),
+            Self::PurpleCards => Some(Color::Purple),
+            Self::ColorlessCards => Some(Color::Colorless),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum DifficultyMod {
     DeadlyEvents,
@@ -1,9 +1,11 @@
 use std::str::FromStr;
 
+use alloc::string::String;
+
 use libgdx_xs128::rng::Random;
 use libgdx_xs128::SeedInitializer;
 
-use crate::neow::NeowBonuses;
+use crate::neow::{Blessing, NeowBonuses};
 use crate::seed::Seed;
 use crate::seed::SeedString;
 
@@ -21,6 +23,7 @@ pub enum Error {
     ParseCharacter(crate::character::try_from::Error),
     ParseDailyModifier(crate::daily::modifier::Error),
     ParseSeedString(crate::seed::from::Error),
+    ParseBlessing(String),
     InconsistentSeeds(Seed, SeedString),
 }
 
@@ -72,6 +75,12 @@ impl FromStr for Daily {
 
         let no_combat_paths = splits.next();
         let no_combat_paths_ascension = splits.next();
+        // the blessing depends on the previous run, not the daily, so it is an optional last column
+        let blessing = match splits.next() {
+            None | Some("" | "Full") => Blessing::Full,
+            Some("Mini") => Blessing::Mini,
+            Some(other) => return Err(Error::ParseBlessing(other.into())),
+        };
 
         let seed_initializer: SeedInitializer = seed.seed.into();
         let rng: Random = seed_initializer.into();
@@ -88,6 +97,7 @@ impl FromStr for Daily {
             no_combat_paths: no_combat_paths.map(Into::into),
             no_combat_paths_ascension: no_combat_paths_ascension.map(Into::into),
             neow_bonuses,
+            blessing,
         })
     }
 }
@@ -1,10 +1,10 @@
-use libgdx_xs128::{rng::Random, RandomXS128};
+use libgdx_xs128::RandomXS128;
 
-use crate::seed::Seed;
+use crate::{rng::Stream, seed::Seed};
 
 impl Seed {
     pub fn juzuless_path(&self, path: [usize; 3]) -> bool {
-        let mut rng = Random::new(self.seed as u64);
+        let mut rng = Stream::Event.rng(self);
         const COMBAT_INCREMENT: f32 = 0.1f32;
         for length in path {
             let mut combat_threshold = COMBAT_INCREMENT;
@@ -0,0 +1,167 @@
+use crate::seed::Seed;
+
+use super::SeedFilter;
+
+pub struct And<A: SeedFilter, B: SeedFilter>(pub A, pub B);
+
+pub struct Or<A: SeedFilter, B: SeedFilter>(pub A, pub B);
+
+pub struct Not<A: SeedFilter>(pub A);
+
+// like `And` and `Or`, but always run the left operand first
+pub struct AndThen<A: SeedFilter, B: SeedFilter>(pub A, pub B);
+
+pub struct OrElse<A: SeedFilter, B: SeedFilter>(pub A, pub B);
+
+impl<A: SeedFilter, B: SeedFilter> SeedFilter for And<A, B> {
+    const COST: u32 = A::COST + B::COST;
+
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+        let seed: Seed = seed.into();
+        if A::COST <= B::COST {
+            self.0.reject(seed.clone()) || self.1.reject(seed)
+        } else {
+            self.1.reject(seed.clone()) || self.0.reject(seed)
+        }
+    }
+}
+
+impl<A: SeedFilter, B: SeedFilter> SeedFilter for Or<A, B> {
+    const COST: u32 = A::COST + B::COST;
+
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+        let seed: Seed = seed.into();
+        if A::COST <= B::COST {
+            self.0.reject(seed.clone()) && self.1.reject(seed)
+        } else {
+            self.1.reject(seed.clone()) && self.0.reject(seed)
+        }
+    }
+}
+
+impl<A: SeedFilter> SeedFilter for Not<A> {
+    const COST: u32 = A::COST;
+
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+        !self.0.reject(seed)
+    }
+}
+
+impl<A: SeedFilter, B: SeedFilter> SeedFilter for AndThen<A, B> {
+    const COST: u32 = A::COST + B::COST;
+
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+        let seed: Seed = seed.into();
+        self.0.reject(seed.clone()) || self.1.reject(seed)
+    }
+}
+
+impl<A: SeedFilter, B: SeedFilter> SeedFilter for OrElse<A, B> {
+    const COST: u32 = A::COST + B::COST;
+
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+        let seed: Seed = seed.into();
+        self.0.reject(seed.clone()) && self.1.reject(seed)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod combinator_tests {
+    use core::cell::RefCell;
+
+    use alloc::vec::Vec;
+
+    use crate::{
+        card::reward::filters::CardRewardFilter,
+        card::Card,
+        character::Character,
+        filter::{MAP_COST, RNG_COST},
+        map::{
+            filters::bottleneck::Bottleneck, in_neighborhood::in_vec::InVec,
+            out_neighborhood::out_vec::OutVec, _ONE_PATH_BURNING_ELITE_BOTTLENECKS,
+        },
+    };
+
+    use super::*;
+
+    const BOTTLENECK: Bottleneck<InVec, OutVec> = Bottleneck::const_default();
+    const CARDS: CardRewardFilter<'_, 3> =
+        CardRewardFilter::new(Character::Silent, None, &[Card::Prepared]);
+
+    #[test]
+    fn test_combinators_match_operands() {
+        const AND: And<Bottleneck<InVec, OutVec>, CardRewardFilter<'_, 3>> = And(BOTTLENECK, CARDS);
+        const OR: Or<Bottleneck<InVec, OutVec>, CardRewardFilter<'_, 3>> = Or(BOTTLENECK, CARDS);
+        const NOT: Not<Bottleneck<InVec, OutVec>> = Not(BOTTLENECK);
+        const AND_THEN: AndThen<CardRewardFilter<'_, 3>, Bottleneck<InVec, OutVec>> =
+            AndThen(CARDS, BOTTLENECK);
+        const OR_ELSE: OrElse<CardRewardFilter<'_, 3>, Bottleneck<InVec, OutVec>> =
+            OrElse(CARDS, BOTTLENECK);
+        const NESTED: Not<And<Bottleneck<InVec, OutVec>, Not<CardRewardFilter<'_, 3>>>> =
+            Not(And(BOTTLENECK, Not(CARDS)));
+        let seeds = _ONE_PATH_BURNING_ELITE_BOTTLENECKS
+            .iter()
+            .map(|&seed| Seed::from(seed))
+            .chain((1i64..200).map(Seed::from));
+        for seed in seeds {
+            let bottleneck = BOTTLENECK.reject(seed.clone());
+            let cards = CARDS.reject(seed.clone());
+            assert_eq!(AND.reject(seed.clone()), bottleneck || cards);
+            assert_eq!(AND_THEN.reject(seed.clone()), bottleneck || cards);
+            assert_eq!(OR.reject(seed.clone()), bottleneck && cards);
+            assert_eq!(OR_ELSE.reject(seed.clone()), bottleneck && cards);
+            assert_eq!(NOT.reject(seed.clone()), !bottleneck);
+            let inner = bottleneck || !cards;
+            assert_eq!(NESTED.reject(seed), !inner);
+        }
+    }
+
+    struct Logged<'a, const COST: u32> {
+        name: &'static str,
+        rejects: bool,
+        log: &'a RefCell<Vec<(&'static str, i64)>>,
+    }
+
+    impl<'a, const COST: u32> SeedFilter for Logged<'a, COST> {
+        const COST: u32 = COST;
+
+        fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+            self.log.borrow_mut().push((self.name, seed.into().seed));
+            self.rejects
+        }
+    }
+
+    #[test]
+    fn test_cheapest_operand_runs_first() {
+        let log = RefCell::new(Vec::new());
+        let cheap = |rejects| Logged::<RNG_COST> {
+            name: "cheap",
+            rejects,
+            log: &log,
+        };
+        let expensive = |rejects| Logged::<MAP_COST> {
+            name: "expensive",
+            rejects,
+            log: &log,
+        };
+
+        assert!(And(expensive(true), cheap(true)).reject(7i64));
+        assert_eq!(log.take(), [("cheap", 7)]);
+
+        assert!(!Or(expensive(false), cheap(false)).reject(7i64));
+        assert_eq!(log.take(), [("cheap", 7)]);
+
+        assert!(And(expensive(false), cheap(true)).reject(7i64));
+        assert_eq!(log.take(), [("cheap", 7)]);
+
+        assert!(!And(expensive(false), cheap(false)).reject(7i64));
+        assert_eq!(log.take(), [("cheap", 7), ("expensive", 7)]);
+
+        assert!(AndThen(expensive(true), cheap(true)).reject(7i64));
+        assert_eq!(log.take(), [("expensive", 7)]);
+
+        assert!(!OrElse(expensive(false), cheap(false)).reject(7i64));
+        assert_eq!(log.take(), [("expensive", 7)]);
+    }
+}
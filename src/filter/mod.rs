@@ -2,11 +2,34 @@ use libgdx_xs128::{rng::Random, RandomXS128};
 
 use crate::seed::Seed;
 
+pub mod combinator;
+
+// rough relative costs, used to run the cheapest operand of a combinator first
+pub const RNG_COST: u32 = 1;
+pub const SKELETON_COST: u32 = 8;
+pub const MAP_COST: u32 = 16;
+
 pub trait SeedFilter {
+    const COST: u32 = RNG_COST;
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool;
+}
+
+// a filter that reads a single stream; combinators only implement `SeedFilter`,
+// since their operands may each need a different stream of the same seed
+pub trait RngFilter {
+    const COST: u32 = RNG_COST;
     fn reject_rng(&self, rng: &mut Random) -> bool;
+    fn rng(&self, seed: &Seed) -> Random {
+        Random::new(seed.seed as u64)
+    }
+}
+
+impl<F: RngFilter> SeedFilter for F {
+    const COST: u32 = <F as RngFilter>::COST;
+
     fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
         let seed: Seed = seed.into();
-        let mut rng = Random::new(seed.seed as u64);
+        let mut rng = self.rng(&seed);
         self.reject_rng(&mut rng)
     }
 }
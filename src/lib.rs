@@ -22,8 +22,19 @@ pub mod map;
 
 pub mod neow;
 
+pub mod potion;
+
+pub mod relic;
+
+pub mod rng;
+
 pub mod seed;
 
+#[cfg(feature = "json")]
+pub mod settings;
+
+pub mod shop;
+
 #[cfg(feature = "std")]
 pub mod sieve;
 
@@ -1,10 +1,12 @@
 use alloc::vec::Vec;
 use libgdx_xs128::{rng::Random, RandomXS128};
 
-use crate::map::{in_neighborhood::InNeighborhood, out_neighborhood::OutNeighborhood, Map};
+use crate::map::{in_neighborhood::InNeighborhood, out_neighborhood::OutNeighborhood, Act, Map};
+use crate::seed::Seed;
 
 use super::kind::NodeKind;
 
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EliteBuff {
     Strength,
@@ -13,7 +15,7 @@ pub enum EliteBuff {
     Regenerate,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EliteInfo {
     pub buff_x: usize,
     pub buff_y: usize,
@@ -22,6 +24,8 @@ pub struct EliteInfo {
     pub count: usize,
 }
 
+pub const BURNING_ELITE_ACTS: [Act; 3] = [Act::One, Act::Two, Act::Three];
+
 impl<const PATHS: usize, In, Out> Map<PATHS, In, Out>
 where
     In: for<'a> InNeighborhood<'a>,
@@ -80,3 +84,57 @@ where
         }
     }
 }
+
+impl<const PATHS: usize, In, Out> Map<PATHS, In, Out>
+where
+    In: for<'a> InNeighborhood<'a> + Default,
+    Out: for<'a> OutNeighborhood<'a> + Default,
+{
+    // the emerald key elite is re-rolled each act until the key is taken
+    pub fn burning_elites(seed: &Seed, ascension: bool) -> [Option<EliteInfo>; 3] {
+        BURNING_ELITE_ACTS.map(|act| {
+            let mut rng = seed.map_rng(act);
+            let map = Self::generate_act(&mut rng, act, ascension);
+            map.burning_elite(&mut rng)
+        })
+    }
+}
+
+#[cfg(test)]
+mod burning_elite_tests {
+    use std::dbg;
+
+    use crate::map::{
+        in_neighborhood::in_vec::InVec, out_neighborhood::out_vec::OutVec,
+        _ONE_PATH_BURNING_ELITE_BOTTLENECKS,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_act_one_burning_elite_matches_map() {
+        for &seed in _ONE_PATH_BURNING_ELITE_BOTTLENECKS {
+            let seed: Seed = seed.into();
+            let mut rng = seed.map_rng(Act::One);
+            let map = Map::<6, InVec, OutVec>::generate(&mut rng, true);
+            let burning_elite = map.burning_elite(&mut rng);
+            let burning_elites = Map::<6, InVec, OutVec>::burning_elites(&seed, true);
+            dbg!(&burning_elites);
+            assert_eq!(burning_elite, burning_elites[0]);
+        }
+    }
+
+    #[test]
+    fn test_burning_elites_are_elites() {
+        for seed in 1i64..100 {
+            let seed = Seed::from(seed);
+            let burning_elites = Map::<6, InVec, OutVec>::burning_elites(&seed, true);
+            for (act, burning_elite) in BURNING_ELITE_ACTS.into_iter().zip(burning_elites) {
+                let mut rng = seed.map_rng(act);
+                let map = Map::<6, InVec, OutVec>::generate_act(&mut rng, act, true);
+                let EliteInfo { buff_x, buff_y, .. } = burning_elite.unwrap();
+                assert!(map.kinds(buff_y).nth(buff_x) == Some(&NodeKind::Elite));
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum NodeKind {
     #[default]
     Unassigned,
@@ -8,6 +8,7 @@ pub enum NodeKind {
     Rest,
     Shop,
     Treasure,
+    Boss,
     Empty,
 }
 
@@ -29,6 +30,7 @@ impl NodeKind {
             Self::Rest => 'R',
             Self::Shop => '$',
             Self::Treasure => 'T',
+            Self::Boss => 'B',
             Self::Empty => ' ',
         }
     }
@@ -66,6 +66,7 @@ where
         self.count_final_rest_sites() + self.count_treasure_rooms() + self.count_first_floor()
     }
 
+    // every act sets the same chances in `initializeLevelSpecificChances`
     fn fill_room_array(count: usize, ascension: bool) -> Vec<NodeKind> {
         let chances: [(NodeKind, f32); 4] = [
             (NodeKind::Shop, 0.05),
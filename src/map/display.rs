@@ -8,6 +8,7 @@ impl Display for Act {
             Act::One => "Act I",
             Act::Two => "Act II",
             Act::Three => "Act III",
+            Act::Four => "Act IV",
 })
     }
 }
@@ -1,15 +1,17 @@
 use libgdx_xs128::rng::Random;
-use libgdx_xs128::RandomXS128;
 
 #[cfg(feature = "std")]
 use crate::map::assign_nodes::buffed_elite::EliteBuff;
 use crate::map::in_neighborhood::InNeighborhood;
 use crate::map::out_neighborhood::OutNeighborhood;
 use crate::map::skeleton::Skeleton;
+use crate::map::Act;
 // use crate::map::in_neighborhood::in_vec::InVec;
 // use crate::map::out_neighborhood::out_vec::OutVec;
 use crate::seed::Seed;
-use crate::filter::SeedFilter;
+#[cfg(feature = "std")]
+use crate::filter::MAP_COST;
+use crate::filter::{RngFilter, SKELETON_COST};
 
 pub struct Bottleneck<In, Out>
 where
@@ -35,20 +37,20 @@ where
     }
 }
 
-impl<In, Out> SeedFilter for Bottleneck<In, Out>
+impl<In, Out> RngFilter for Bottleneck<In, Out>
 where
     In: for<'a> InNeighborhood<'a> + Default,
     Out: for<'a> OutNeighborhood<'a> + Default,
 {
+    const COST: u32 = SKELETON_COST;
+
     fn reject_rng(&self, rng: &mut Random) -> bool {
         let map = Skeleton::<6, In, Out>::generate(rng);
-        map.row(self.row).count_out_neighborhoods() != 1
+        map.bottleneck(self.row).is_none()
     }
 
-    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
-        let seed: Seed = seed.into();
-        let mut rng = Random::new(seed.seed as u64 + 1);
-        self.reject_rng(&mut rng)
+    fn rng(&self, seed: &Seed) -> Random {
+        seed.map_rng(Act::One)
     }
 
     // fn reject(&self, seed: &Seed) -> bool {
@@ -71,6 +73,7 @@ where
 pub struct BurningEliteBottleneck<'a> {
     row: usize,
     required_buffs: Option<&'a [EliteBuff]>,
+    ascension: bool,
 }
 
 #[cfg(feature = "std")]
@@ -79,9 +82,15 @@ impl<'a> BurningEliteBottleneck<'a> {
         Self {
             row: floor - 1,
             required_buffs,
+            ascension: true,
         }
     }
 
+    pub const fn with_ascension(mut self, ascension: bool) -> Self {
+        self.ascension = ascension;
+        self
+    }
+
     pub const fn const_default() -> Self {
         Self::new(6, None)
     }
@@ -102,10 +111,12 @@ use crate::map::{
 };
 
 #[cfg(feature = "std")]
-impl<'a> SeedFilter for BurningEliteBottleneck<'a> {
+impl<'a> RngFilter for BurningEliteBottleneck<'a> {
+    const COST: u32 = MAP_COST;
+
     fn reject_rng(&self, rng: &mut Random) -> bool {
-        let map = Map::<6, InVec, OutVec>::generate(rng, true);
-        if map.row(self.row).count_out_neighborhoods() != 1 {
+        let map = Map::<6, InVec, OutVec>::generate(rng, self.ascension);
+        if map.bottleneck(self.row).is_none() {
             return true;
         }
         !map.burning_elite(rng).is_some_and(|info| {
@@ -115,10 +126,8 @@ impl<'a> SeedFilter for BurningEliteBottleneck<'a> {
         })
     }
 
-    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
-        let seed: Seed = seed.into();
-        let mut rng = Random::new(seed.seed as u64 + 1);
-        self.reject_rng(&mut rng)
+    fn rng(&self, seed: &Seed) -> Random {
+        seed.map_rng(Act::One)
     }
 
     // fn reject(&self, seed: &Seed) -> bool {
@@ -127,7 +136,7 @@ impl<'a> SeedFilter for BurningEliteBottleneck<'a> {
     // }
 }
 
-struct OnePath<In, Out>
+pub struct OnePath<In, Out>
 where
     In: for<'a> InNeighborhood<'a> + Default,
     Out: for<'a> OutNeighborhood<'a> + Default,
@@ -160,25 +169,21 @@ where
     }
 }
 
-impl<In, Out> SeedFilter for OnePath<In, Out>
+impl<In, Out> RngFilter for OnePath<In, Out>
 where
     In: for<'a> InNeighborhood<'a> + Default,
     Out: for<'a> OutNeighborhood<'a> + Default,
 {
+    const COST: u32 = SKELETON_COST;
+
     fn reject_rng(&self, rng: &mut Random) -> bool {
         let map = Skeleton::<6, In, Out>::generate(rng);
-        for row in 1..self.length {
-            if map.row(row).count_out_neighborhoods() != 1 {
-                return true;
-            }
-        }
-        false
+        let bottlenecks = map.bottlenecks();
+        bottlenecks[1..self.length].iter().any(Option::is_none)
     }
 
-    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
-        let seed: Seed = seed.into();
-        let mut rng = Random::new(seed.seed as u64 + 1);
-        self.reject_rng(&mut rng)
+    fn rng(&self, seed: &Seed) -> Random {
+        seed.map_rng(Act::One)
     }
 
     // fn reject(&self, seed: &Seed) -> bool {
@@ -190,7 +195,7 @@ where
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod bottleneck_filter_tests {
-    use crate::map::_ONE_PATH_BURNING_ELITE_BOTTLENECKS;
+    use crate::{filter::SeedFilter, map::_ONE_PATH_BURNING_ELITE_BOTTLENECKS};
 
     use super::*;
 
@@ -1 +1,3 @@
 pub mod bottleneck;
+#[cfg(feature = "std")]
+pub mod shape;
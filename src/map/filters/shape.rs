@@ -0,0 +1,320 @@
+use libgdx_xs128::rng::Random;
+
+use crate::filter::{RngFilter, MAP_COST};
+use crate::map::{
+    assign_nodes::kind::NodeKind,
+    in_neighborhood::{in_vec::InVec, InNeighborhood},
+    out_neighborhood::{out_vec::OutVec, OutNeighborhood},
+    path::PathKinds,
+    skeleton::Skeleton,
+    Act, Map, HEIGHT,
+};
+use crate::seed::Seed;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Quantity {
+    pub const fn contains(&self, count: usize) -> bool {
+        match *self {
+            Quantity::Exactly(n) => count == n,
+            Quantity::AtLeast(n) => count >= n,
+            Quantity::AtMost(n) => count <= n,
+        }
+    }
+}
+
+// counts `kind` on the floors strictly before `before_floor`, from 1 up to `HEIGHT + 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KindCount {
+    pub kind: NodeKind,
+    pub count: Quantity,
+    pub before_floor: usize,
+}
+
+impl KindCount {
+    pub const fn new(kind: NodeKind, count: Quantity, before_floor: usize) -> Self {
+        assert!(
+            before_floor >= 1 && before_floor <= HEIGHT + 1,
+            "before_floor must be in 1..=16"
+        );
+        Self {
+            kind,
+            count,
+            before_floor,
+        }
+    }
+
+    pub const fn anywhere(kind: NodeKind, count: Quantity) -> Self {
+        Self::new(kind, count, HEIGHT + 1)
+    }
+
+    pub fn holds(&self, kinds: &PathKinds) -> bool {
+        let floors = &kinds[..self.before_floor.saturating_sub(1).min(HEIGHT)];
+        let count = floors.iter().filter(|&&kind| kind == self.kind).count();
+        self.count.contains(count)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Constraint<'a> {
+    Nodes { floor: usize, count: Quantity },
+    Paths(Quantity),
+    SomePath(&'a [KindCount]),
+    EveryPath(&'a [KindCount]),
+    All(&'a [Constraint<'a>]),
+    Any(&'a [Constraint<'a>]),
+    Not(&'a Constraint<'a>),
+}
+
+impl<'a> Constraint<'a> {
+    pub const fn nodes(floor: usize, count: Quantity) -> Self {
+        assert!(floor >= 1 && floor <= HEIGHT, "floor must be in 1..=15");
+        Self::Nodes { floor, count }
+    }
+
+    pub const fn filter(self, act: Act, ascension: bool) -> MapShape<'a> {
+        MapShape::new(self, act, ascension)
+    }
+
+    // `None` when the room assignment is needed to decide
+    pub fn decide_skeleton<const PATHS: usize, In, Out>(
+        &self,
+        skeleton: &Skeleton<PATHS, In, Out>,
+    ) -> Option<bool>
+    where
+        In: for<'b> InNeighborhood<'b>,
+        Out: for<'b> OutNeighborhood<'b>,
+    {
+        match self {
+            Constraint::Nodes { floor, count } => {
+                let nodes = match floor.checked_sub(1) {
+                    Some(row) if row < HEIGHT => skeleton.count_nodes(row),
+                    _ => 0,
+                };
+                Some(count.contains(nodes))
+            }
+            Constraint::Paths(count) => Some(count.contains(skeleton.count_paths() as usize)),
+            Constraint::SomePath(_) | Constraint::EveryPath(_) => None,
+            Constraint::All(constraints) => {
+                let mut decided = Some(true);
+                for constraint in constraints.iter() {
+                    match constraint.decide_skeleton(skeleton) {
+                        Some(true) => {}
+                        Some(false) => return Some(false),
+                        None => decided = None,
+                    }
+                }
+                decided
+            }
+            Constraint::Any(constraints) => {
+                let mut decided = Some(false);
+                for constraint in constraints.iter() {
+                    match constraint.decide_skeleton(skeleton) {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => decided = None,
+                    }
+                }
+                decided
+            }
+            Constraint::Not(constraint) => constraint.decide_skeleton(skeleton).map(|holds| !holds),
+        }
+    }
+
+    pub fn holds<const PATHS: usize, In, Out>(&self, map: &Map<PATHS, In, Out>) -> bool
+    where
+        In: for<'b> InNeighborhood<'b>,
+        Out: for<'b> OutNeighborhood<'b>,
+    {
+        match self {
+            Constraint::Nodes { .. } | Constraint::Paths(_) => {
+                self.decide_skeleton(map.skeleton()) == Some(true)
+            }
+            Constraint::SomePath(counts) => map.any_path(|path| {
+                let kinds = map.path_kinds(path);
+                counts.iter().all(|count| count.holds(&kinds))
+            }),
+            Constraint::EveryPath(counts) => !map.any_path(|path| {
+                let kinds = map.path_kinds(path);
+                !counts.iter().all(|count| count.holds(&kinds))
+            }),
+            Constraint::All(constraints) => {
+                constraints.iter().all(|constraint| constraint.holds(map))
+            }
+            Constraint::Any(constraints) => {
+                constraints.iter().any(|constraint| constraint.holds(map))
+            }
+            Constraint::Not(constraint) => !constraint.holds(map),
+        }
+    }
+}
+
+pub struct MapShape<'a> {
+    constraint: Constraint<'a>,
+    act: Act,
+    ascension: bool,
+}
+
+impl<'a> MapShape<'a> {
+    pub const fn new(constraint: Constraint<'a>, act: Act, ascension: bool) -> Self {
+        Self {
+            constraint,
+            act,
+            ascension,
+        }
+    }
+}
+
+impl<'a> RngFilter for MapShape<'a> {
+    const COST: u32 = MAP_COST;
+
+    fn reject_rng(&self, rng: &mut Random) -> bool {
+        if let Act::Four = self.act {
+            return !self
+                .constraint
+                .holds(&Map::<6, InVec, OutVec>::the_ending());
+        }
+        let skeleton = Skeleton::<6, InVec, OutVec>::generate(rng);
+        let mut map = Map::from_skeleton(skeleton);
+        if let Some(holds) = self.constraint.decide_skeleton(map.skeleton()) {
+            return !holds;
+        }
+        map.assign_rooms(rng, self.ascension);
+        !self.constraint.holds(&map)
+    }
+
+    fn rng(&self, seed: &Seed) -> Random {
+        seed.map_rng(self.act)
+    }
+}
+
+#[cfg(test)]
+mod shape_filter_tests {
+    use crate::{
+        filter::SeedFilter,
+        map::{
+            filters::bottleneck::{Bottleneck, BurningEliteBottleneck},
+            _ONE_PATH_BURNING_ELITE_BOTTLENECKS,
+        },
+    };
+
+    use super::*;
+
+    const ELITES_AND_SHOPS: [KindCount; 2] = [
+        KindCount::new(NodeKind::Elite, Quantity::AtLeast(3), 15),
+        KindCount::new(NodeKind::Shop, Quantity::AtLeast(2), 15),
+    ];
+
+    #[test]
+    fn test_bottleneck_constraint() {
+        const FILTER: MapShape = Constraint::nodes(6, Quantity::Exactly(1)).filter(Act::One, true);
+        const BOTTLENECK: Bottleneck<InVec, OutVec> = Bottleneck::const_default();
+        for &seed in _ONE_PATH_BURNING_ELITE_BOTTLENECKS {
+            assert!(!FILTER.reject::<Seed>(seed.into()));
+        }
+        for seed in 1u64..1_000 {
+            assert_eq!(FILTER.reject(seed), BOTTLENECK.reject(seed), "{seed}");
+        }
+    }
+
+    #[test]
+    fn test_burning_elite_bottleneck_constraint() {
+        const CONSTRAINTS: [Constraint; 2] = [
+            Constraint::nodes(6, Quantity::Exactly(1)),
+            Constraint::SomePath(&[KindCount::anywhere(NodeKind::Elite, Quantity::AtLeast(1))]),
+        ];
+        const FILTER: MapShape = Constraint::All(&CONSTRAINTS).filter(Act::One, true);
+        const BOTTLENECK: BurningEliteBottleneck = BurningEliteBottleneck::const_default();
+        for seed in 1u64..1_000 {
+            assert_eq!(FILTER.reject(seed), BOTTLENECK.reject(seed), "{seed}");
+        }
+    }
+
+    #[test]
+    fn test_path_constraint() {
+        const FILTER: MapShape = Constraint::SomePath(&ELITES_AND_SHOPS).filter(Act::One, true);
+        for seed in 1i64..200 {
+            let seed = Seed::from(seed);
+            let map = Map::<6, InVec, OutVec>::generate(&mut seed.map_rng(Act::One), true);
+            let expected = map.paths().iter().any(|path| {
+                let kinds = map.path_kinds(path);
+                let elites = kinds
+                    .iter()
+                    .filter(|&&kind| kind == NodeKind::Elite)
+                    .count();
+                let shops = kinds[..14]
+                    .iter()
+                    .filter(|&&kind| kind == NodeKind::Shop)
+                    .count();
+                elites >= 3 && shops >= 2
+            });
+            assert_eq!(FILTER.reject(seed), !expected);
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_floors() {
+        const OFF_THE_MAP: [Constraint; 2] = [
+            Constraint::Nodes {
+                floor: 0,
+                count: Quantity::Exactly(0),
+            },
+            Constraint::Nodes {
+                floor: HEIGHT + 1,
+                count: Quantity::Exactly(0),
+            },
+        ];
+        const FILTER: MapShape = Constraint::All(&OFF_THE_MAP).filter(Act::One, true);
+        let before_nothing = KindCount {
+            kind: NodeKind::Monster,
+            count: Quantity::Exactly(0),
+            before_floor: 0,
+        };
+        for seed in 1i64..20 {
+            let seed = Seed::from(seed);
+            assert!(!FILTER.reject(seed.clone()));
+            let map = Map::<6, InVec, OutVec>::generate(&mut seed.map_rng(Act::One), true);
+            map.for_each_path(|path| assert!(before_nothing.holds(&map.path_kinds(path))));
+        }
+    }
+
+    #[test]
+    fn test_the_ending_shape() {
+        const FLOORS: [Constraint; 4] = [
+            Constraint::nodes(1, Quantity::Exactly(1)),
+            Constraint::nodes(4, Quantity::Exactly(1)),
+            Constraint::nodes(5, Quantity::Exactly(0)),
+            Constraint::Paths(Quantity::Exactly(0)),
+        ];
+        const FILTER: MapShape = Constraint::All(&FLOORS).filter(Act::Four, true);
+        const SOME_PATH: MapShape = Constraint::SomePath(&ELITES_AND_SHOPS).filter(Act::Four, true);
+        for seed in 1u64..20 {
+            assert!(!FILTER.reject(seed));
+            assert!(SOME_PATH.reject(seed));
+        }
+    }
+
+    #[test]
+    fn test_skeleton_short_circuit() {
+        const NOT_BOTTLENECK: Constraint =
+            Constraint::Not(&Constraint::nodes(6, Quantity::AtMost(1)));
+        const CONSTRAINTS: [Constraint; 2] =
+            [NOT_BOTTLENECK, Constraint::SomePath(&ELITES_AND_SHOPS)];
+        for seed in 1i64..200 {
+            let seed = Seed::from(seed);
+            let skeleton = Skeleton::<6, InVec, OutVec>::generate(&mut seed.map_rng(Act::One));
+            let map = Map::from_skeleton(skeleton);
+            let decided = Constraint::All(&CONSTRAINTS).decide_skeleton(map.skeleton());
+            if map.skeleton().count_nodes(5) == 1 {
+                assert_eq!(decided, Some(false));
+            } else {
+                assert_eq!(decided, None);
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ pub mod display;
 pub mod filters;
 pub mod in_neighborhood;
 pub mod out_neighborhood;
+pub mod path;
 pub mod row;
 pub mod skeleton;
 
@@ -30,6 +31,14 @@ pub const REST_ROW: usize = HEIGHT - 1;
 pub const BEFORE_REST_ROW: usize = REST_ROW - 1;
 pub const TREASURE_ROW: usize = 8;
 
+pub const THE_ENDING_POSITION: usize = 3;
+pub const THE_ENDING: [NodeKind; 4] = [
+    NodeKind::Rest,
+    NodeKind::Shop,
+    NodeKind::Elite,
+    NodeKind::Boss,
+];
+
 #[derive(Default)]
 pub struct Map<const PATHS: usize, In, Out>
 where
@@ -40,21 +49,22 @@ where
     kinds: [[NodeKind; WIDTH as usize]; HEIGHT],
 }
 
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum Act {
     #[default]
     One,
     Two,
     Three,
-    // Four,
+    Four,
 }
 
 impl Act {
     const fn seed_offset(&self) -> i64 {
         match self {
             Act::One => 1,
-            Act::Two => todo!(),
-            Act::Three => todo!(),
+            Act::Two => 200,
+            Act::Three => 600,
+            Act::Four => 1200,
         }
     }
 }
@@ -98,6 +108,10 @@ where
     pub fn kinds(&self, row: usize) -> impl Iterator<Item = &NodeKind> {
         self.kinds[row].iter()
     }
+
+    pub fn skeleton(&self) -> &Skeleton<PATHS, In, Out> {
+        &self.skeleton
+    }
 }
 
 #[cfg(feature = "std")]
@@ -107,13 +121,15 @@ where
     Out: for<'a> OutNeighborhood<'a> + Default,
 {
     pub fn generate(rng: &mut Random, ascension: bool) -> Map<PATHS, In, Out> {
-        let skeleton = Skeleton::generate(rng);
-        let mut map = Self {
-            skeleton,
-            kinds: [[NodeKind::default(); WIDTH as usize]; HEIGHT],
-        };
+        Self::generate_act(rng, Act::One, ascension)
+    }
 
-        map.filter_redundant_edges_from_first_row();
+    pub fn generate_act(rng: &mut Random, act: Act, ascension: bool) -> Map<PATHS, In, Out> {
+        if let Act::Four = act {
+            return Self::the_ending();
+        }
+        let skeleton = Skeleton::generate(rng);
+        let mut map = Self::from_skeleton(skeleton);
         map.assign_rooms(rng, ascension);
 
         // let mut map = Map::default();
@@ -124,6 +140,30 @@ where
 
         map
     }
+
+    pub fn from_skeleton(skeleton: Skeleton<PATHS, In, Out>) -> Map<PATHS, In, Out> {
+        let mut map = Self {
+            skeleton,
+            kinds: [[NodeKind::default(); WIDTH as usize]; HEIGHT],
+        };
+        map.filter_redundant_edges_from_first_row();
+        map
+    }
+
+    pub fn the_ending() -> Map<PATHS, In, Out> {
+        let mut map = Self {
+            skeleton: Skeleton::default(),
+            kinds: [[NodeKind::default(); WIDTH as usize]; HEIGHT],
+        };
+        for (row, kind) in THE_ENDING.into_iter().enumerate() {
+            if row + 1 < THE_ENDING.len() {
+                map.skeleton
+                    .add_edge(row, THE_ENDING_POSITION, THE_ENDING_POSITION);
+            }
+            map.set_kind(row, THE_ENDING_POSITION, kind);
+        }
+        map
+    }
 }
 
 #[cfg(feature = "std")]
@@ -163,6 +203,31 @@ mod map_tests {
         dbg!(burning_elite_buff);
     }
 
+    #[test]
+    fn print_the_ending() {
+        let mut rng = Seed::from(1i64).map_rng(Act::Four);
+        let map: Map<6, InVec, OutVec> = Map::generate_act(&mut rng, Act::Four, true);
+        println!("{map}");
+        for (row, kind) in THE_ENDING.iter().enumerate() {
+            assert!(map.kinds(row).eq((0..WIDTH as usize).map(|position| {
+                if position == THE_ENDING_POSITION {
+                    kind
+                } else {
+                    &NodeKind::Unassigned
+                }
+            })));
+        }
+        let boss_row = THE_ENDING.len() - 1;
+        assert!(map
+            .row(boss_row)
+            .out_neighborhood(THE_ENDING_POSITION)
+            .is_empty());
+        assert!(map
+            .row(boss_row + 1)
+            .in_neighborhood(THE_ENDING_POSITION)
+            .is_empty());
+    }
+
     #[test]
     fn print_one_path_burning_elite_bottleneck_maps() {
         // const SEEDS: &[i64] = &[
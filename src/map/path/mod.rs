@@ -0,0 +1,338 @@
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+
+use super::{
+    assign_nodes::kind::NodeKind, in_neighborhood::InNeighborhood,
+    out_neighborhood::OutNeighborhood, skeleton::Skeleton, Map, HEIGHT, REST_ROW, WIDTH,
+};
+
+pub type Path = [usize; HEIGHT];
+pub type PathKinds = [NodeKind; HEIGHT];
+
+impl<const PATHS: usize, In, Out> Skeleton<PATHS, In, Out>
+where
+    In: for<'a> InNeighborhood<'a>,
+    Out: for<'a> OutNeighborhood<'a>,
+{
+    pub fn count_paths(&self) -> u64 {
+        self.paths_from(0).iter().sum()
+    }
+
+    // the number of paths from each position of `row` to the rest row
+    fn paths_from(&self, row: usize) -> [u64; WIDTH as usize] {
+        let mut counts = [1u64; WIDTH as usize];
+        for row in (row..REST_ROW).rev() {
+            let mut next_counts = [0u64; WIDTH as usize];
+            for (position, count) in next_counts.iter_mut().enumerate() {
+                *count = self
+                    .row(row)
+                    .out_neighborhood(position)
+                    .iter()
+                    .map(|&next_position| counts[next_position])
+                    .sum();
+            }
+            counts = next_counts;
+        }
+        counts
+    }
+
+    // the number of paths from the first row to each position of `row`
+    fn paths_to(&self, row: usize) -> [u64; WIDTH as usize] {
+        let mut counts = [1u64; WIDTH as usize];
+        for row in 0..row {
+            let mut next_counts = [0u64; WIDTH as usize];
+            for (position, &count) in counts.iter().enumerate() {
+                for &next_position in self.row(row).out_neighborhood(position).iter() {
+                    next_counts[next_position] += count;
+                }
+            }
+            counts = next_counts;
+        }
+        counts
+    }
+
+    pub fn count_paths_through(&self, row: usize, position: usize) -> u64 {
+        self.paths_to(row)[position] * self.paths_from(row)[position]
+    }
+
+    /// The position on `row` that every path passes through, if there is one.
+    pub fn bottleneck(&self, row: usize) -> Option<usize> {
+        shared_position(&self.paths_to(row), &self.paths_from(row))
+    }
+
+    /// The bottleneck of every row, from one pass over the map each way.
+    pub fn bottlenecks(&self) -> [Option<usize>; HEIGHT] {
+        let mut from = [[1u64; WIDTH as usize]; HEIGHT];
+        for row in (0..REST_ROW).rev() {
+            for position in 0..WIDTH as usize {
+                from[row][position] = self
+                    .row(row)
+                    .out_neighborhood(position)
+                    .iter()
+                    .map(|&next_position| from[row + 1][next_position])
+                    .sum();
+            }
+        }
+        let mut to = [1u64; WIDTH as usize];
+        core::array::from_fn(|row| {
+            if row > 0 {
+                let mut next_to = [0u64; WIDTH as usize];
+                for (position, &count) in to.iter().enumerate() {
+                    for &next_position in self.row(row - 1).out_neighborhood(position).iter() {
+                        next_to[next_position] += count;
+                    }
+                }
+                to = next_to;
+            }
+            shared_position(&to, &from[row])
+        })
+    }
+
+    pub fn for_each_path<F: FnMut(&Path)>(&self, mut f: F) {
+        let mut path = [0; HEIGHT];
+        for position in 0..WIDTH as usize {
+            self.visit_paths(0, position, &mut path, &mut f);
+        }
+    }
+
+    fn visit_paths<F: FnMut(&Path)>(
+        &self,
+        row: usize,
+        position: usize,
+        path: &mut Path,
+        f: &mut F,
+    ) {
+        path[row] = position;
+        if row == REST_ROW {
+            f(path);
+            return;
+        }
+        for &next_position in self.row(row).out_neighborhood(position).iter() {
+            self.visit_paths(row + 1, next_position, path, f);
+        }
+    }
+
+    pub fn any_path<F: FnMut(&Path) -> bool>(&self, mut f: F) -> bool {
+        let mut path = [0; HEIGHT];
+        (0..WIDTH as usize).any(|position| self.find_path(0, position, &mut path, &mut f))
+    }
+
+    fn find_path<F: FnMut(&Path) -> bool>(
+        &self,
+        row: usize,
+        position: usize,
+        path: &mut Path,
+        f: &mut F,
+    ) -> bool {
+        path[row] = position;
+        if row == REST_ROW {
+            return f(path);
+        }
+        self.row(row)
+            .out_neighborhood(position)
+            .iter()
+            .any(|&next_position| self.find_path(row + 1, next_position, path, f))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn paths(&self) -> Vec<Path> {
+        let mut paths = Vec::new();
+        self.for_each_path(|path| paths.push(*path));
+        paths.sort();
+        paths
+    }
+}
+
+// every path crosses a row once, so the paths through its positions add up to all of them
+fn shared_position(to: &[u64; WIDTH as usize], from: &[u64; WIDTH as usize]) -> Option<usize> {
+    let through = |position: usize| to[position] * from[position];
+    let total: u64 = (0..WIDTH as usize).map(through).sum();
+    if total == 0 {
+        return None;
+    }
+    (0..WIDTH as usize).find(|&position| through(position) == total)
+}
+
+impl<const PATHS: usize, In, Out> Map<PATHS, In, Out>
+where
+    In: for<'a> InNeighborhood<'a>,
+    Out: for<'a> OutNeighborhood<'a>,
+{
+    pub fn count_paths(&self) -> u64 {
+        self.skeleton.count_paths()
+    }
+
+    pub fn count_paths_through(&self, row: usize, position: usize) -> u64 {
+        self.skeleton.count_paths_through(row, position)
+    }
+
+    pub fn bottleneck(&self, row: usize) -> Option<usize> {
+        self.skeleton.bottleneck(row)
+    }
+
+    pub fn bottlenecks(&self) -> [Option<usize>; HEIGHT] {
+        self.skeleton.bottlenecks()
+    }
+
+    pub fn for_each_path<F: FnMut(&Path)>(&self, f: F) {
+        self.skeleton.for_each_path(f)
+    }
+
+    pub fn any_path<F: FnMut(&Path) -> bool>(&self, f: F) -> bool {
+        self.skeleton.any_path(f)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn paths(&self) -> Vec<Path> {
+        self.skeleton.paths()
+    }
+
+    pub fn path_kinds(&self, path: &Path) -> PathKinds {
+        core::array::from_fn(|row| *self.kind(row, path[row]))
+    }
+
+    pub fn best_path<T, F>(&self, mut score: F) -> Option<(Path, T)>
+    where
+        T: Ord,
+        F: FnMut(&PathKinds) -> Option<T>,
+    {
+        let mut best: Option<(Path, T)> = None;
+        self.for_each_path(|path| {
+            let Some(path_score) = score(&self.path_kinds(path)) else {
+                return;
+            };
+            let improves = match &best {
+                Some((best_path, best_score)) => {
+                    path_score > *best_score || (path_score == *best_score && path < best_path)
+                }
+                None => true,
+            };
+            if improves {
+                best = Some((*path, path_score));
+            }
+        });
+        best
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod path_tests {
+    use core::cmp::Reverse;
+
+    use crate::{
+        map::{
+            in_neighborhood::{in_byte::InByte, in_vec::InVec},
+            out_neighborhood::{out_byte::OutByte, out_vec::OutVec},
+            Act, _ONE_PATH_BURNING_ELITE_BOTTLENECKS,
+        },
+        seed::Seed,
+    };
+
+    use super::*;
+
+    fn map(seed: Seed) -> Map<6, InVec, OutVec> {
+        Map::generate(&mut seed.map_rng(Act::One), true)
+    }
+
+    #[test]
+    fn test_count_paths() {
+        for seed in 1i64..100 {
+            let map = map(seed.into());
+            let paths = map.paths();
+            assert_eq!(map.count_paths(), paths.len() as u64);
+            for path in paths {
+                for row in 0..REST_ROW {
+                    assert!(map
+                        .row(row)
+                        .out_neighborhood(path[row])
+                        .iter()
+                        .any(|&next_position| next_position == path[row + 1]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_paths_agree_across_neighborhoods() {
+        for seed in 1i64..20 {
+            let seed = Seed::from(seed);
+            let byte_map: Map<6, InByte, OutByte> =
+                Map::generate(&mut seed.map_rng(Act::One), true);
+            assert_eq!(map(seed).paths(), byte_map.paths());
+        }
+    }
+
+    #[test]
+    fn test_one_path_bottlenecks_share_floors() {
+        for &seed in _ONE_PATH_BURNING_ELITE_BOTTLENECKS {
+            let map = map(seed.into());
+            let paths = map.paths();
+            for row in 1..6 {
+                assert!(paths.iter().all(|path| path[row] == paths[0][row]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bottleneck_matches_paths() {
+        for seed in 1i64..100 {
+            let map = map(seed.into());
+            let paths = map.paths();
+            for row in 0..HEIGHT {
+                let through = |position| paths.iter().filter(|path| path[row] == position).count();
+                for position in 0..WIDTH as usize {
+                    assert_eq!(
+                        map.count_paths_through(row, position),
+                        through(position) as u64
+                    );
+                }
+                let shared = paths.iter().all(|path| path[row] == paths[0][row]);
+                assert_eq!(map.bottleneck(row), shared.then_some(paths[0][row]));
+            }
+            assert_eq!(
+                map.bottlenecks(),
+                core::array::from_fn(|row| map.bottleneck(row))
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_path() {
+        for seed in 1i64..100 {
+            let map = map(seed.into());
+            let count = |kinds: &PathKinds, kind: NodeKind| {
+                kinds.iter().filter(|&&other| other == kind).count()
+            };
+            let (path, elites) = map
+                .best_path(|kinds| Some(count(kinds, NodeKind::Elite)))
+                .unwrap();
+            assert_eq!(count(&map.path_kinds(&path), NodeKind::Elite), elites);
+            map.for_each_path(|other| {
+                assert!(count(&map.path_kinds(other), NodeKind::Elite) <= elites)
+            });
+
+            let (_, Reverse(monsters)) = map
+                .best_path(|kinds| Some(Reverse(count(kinds, NodeKind::Monster))))
+                .unwrap();
+            map.for_each_path(|other| {
+                assert!(count(&map.path_kinds(other), NodeKind::Monster) >= monsters)
+            });
+
+            let early_shop =
+                map.best_path(|kinds| kinds[..5].contains(&NodeKind::Shop).then_some(()));
+            let has_early_shop = map
+                .paths()
+                .iter()
+                .any(|path| map.path_kinds(path)[..5].contains(&NodeKind::Shop));
+            assert_eq!(early_shop.is_some(), has_early_shop);
+        }
+    }
+
+    #[test]
+    fn test_the_ending_has_no_paths() {
+        let map: Map<6, InVec, OutVec> = Map::the_ending();
+        assert_eq!(map.count_paths(), 0);
+        assert!(map.paths().is_empty());
+    }
+}
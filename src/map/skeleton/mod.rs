@@ -46,6 +46,14 @@ where
     fn _rows(&self) -> &[Row<In, Out>; HEIGHT] {
         &self.rows
     }
+
+    pub fn count_nodes(&self, row: usize) -> usize {
+        if row == 0 {
+            self.row(0).count_out_neighborhoods()
+        } else {
+            self.row(row).count_in_neighborhoods()
+        }
+    }
 }
 
 impl<const PATHS: usize, In, Out> Skeleton<PATHS, In, Out>
@@ -72,7 +80,7 @@ where
         first_position
     }
 
-    fn add_edge(&mut self, row: usize, position: usize, next_position: usize) {
+    pub(super) fn add_edge(&mut self, row: usize, position: usize, next_position: usize) {
         // dbg!(row, position, next_position);
         let out_neighborhood = self.row_mut(row).out_neighborhood_mut(position);
         // println!("out: [{out_neighborhood}] + {next_position} =");
@@ -9,7 +9,7 @@ use alloc::{string::{ToString, String}, format};
 use libgdx_xs128::{rng::Random, RandomXS128};
 
 use crate::{
-    map::{in_neighborhood::in_vec::InVec, out_neighborhood::out_vec::OutVec, Map},
+    map::{in_neighborhood::in_vec::InVec, out_neighborhood::out_vec::OutVec, Act, Map},
     seed::{Seed, SeedString},
 };
 
@@ -62,6 +62,64 @@ fn write_maps_to_file() {
     assert_eq!(approved, received, "");
 }
 
+// `TheCity` and `TheBeyond` seed `mapRng` with `seed + 200` and `seed + 600`, so these are the
+// maps the game draws for the same seeds as Act I
+#[test]
+fn write_act_two_maps_to_file() {
+    write_act_maps_to_file(Act::Two, 200, "act_two");
+}
+
+#[test]
+fn write_act_three_maps_to_file() {
+    write_act_maps_to_file(Act::Three, 600, "act_three");
+}
+
+fn write_act_maps_to_file(act: Act, offset: i64, directory: &str) {
+    let received = PathBuf::from(format!(".map_tests/{directory}/received.txt"));
+    let mut received = File::create(&received).unwrap();
+
+    for seed in BAD_PATH_SEEDS {
+        let seed_string: SeedString = seed.parse().unwrap();
+        let seed: Seed = seed_string.clone().into();
+        let mut rng = Random::new(seed.seed.wrapping_add(offset) as u64);
+        let map: Map<6, InVec, OutVec> = Map::generate_act(&mut rng, act, true);
+        let map_string = format!("{seed_string},{seed:?}\n{map}\n\n");
+        received.write_all(map_string.as_bytes()).unwrap();
+    }
+
+    for (i, seed) in (1u64..100).enumerate() {
+        let seed = Seed::from(seed);
+        let seed_string = SeedString::from(seed.clone());
+        let mut rng = Random::new(seed.seed.wrapping_add(offset) as u64);
+        let ascension = i % 2 == 0;
+        let map: Map<6, InVec, OutVec> = Map::generate_act(&mut rng, act, ascension);
+        let map_string = format!("{seed_string},{seed:?}\n{map}\n\n");
+        received.write_all(map_string.as_bytes()).unwrap();
+    }
+
+    let approved = PathBuf::from(format!(".map_tests/{directory}/approved.txt"));
+    let approved = fs::read_to_string(&approved).unwrap();
+
+    let received = PathBuf::from(format!(".map_tests/{directory}/received.txt"));
+    let received = fs::read_to_string(&received).unwrap();
+
+    assert_eq!(approved, received, "{act}");
+}
+
+// `TheEnding` seeds `mapRng` with `seed + 1200`
+#[test]
+fn act_maps_use_game_seed_offsets() {
+    for (act, offset) in [(Act::Two, 200), (Act::Three, 600), (Act::Four, 1200)] {
+        for seed in BAD_PATH_SEEDS {
+            let seed_string: SeedString = seed.parse().unwrap();
+            let seed: Seed = seed_string.into();
+            let mut act_rng = seed.map_rng(act);
+            let mut rng = Random::new(seed.seed.wrapping_add(offset) as u64);
+            assert_eq!(act_rng.next_u64(), rng.next_u64(), "{act}, {seed:?}");
+        }
+    }
+}
+
 #[test]
 fn compare_invec_to_inbytes() {
     for seed in (533907583096i64 + 3..533907583096 + 10) {
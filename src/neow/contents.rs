@@ -0,0 +1,247 @@
+use libgdx_xs128::{rng::Random, RandomXS128};
+
+use crate::{
+    card::{
+        pool::ColorlessCards,
+        reward::{CardRewarder, Offset, RewardContext},
+        transform::{TransformSource, TransformedCards, Transformer},
+        Card, Rarity,
+    },
+    character::Character,
+    relic::{pool::RelicPools, Relic, Tier},
+    rng::{random_boolean, Stream},
+    seed::Seed,
+    unlock::Unlocks,
+};
+
+use super::{FirstBonus, NeowBonuses, SecondBonus, ThirdBonus};
+
+// one roll per option and one for the drawback
+pub const OPTION_ROLLS: u32 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NeowContent {
+    CardChoice([Card; 3]),
+    Card(Card),
+    // the two cards `TransformTwoCards` turns a pair of basics into
+    Cards([Card; 2]),
+    Relic(Relic),
+}
+
+// what each option hands out if it is the one picked; options the seed does not decide,
+// like removals or gold, are `None`, and so is the curse, which the game draws unseeded;
+// transforms assume basics are picked, which are never in the pool they draw from
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct NeowContents {
+    pub first: Option<NeowContent>,
+    pub second: Option<NeowContent>,
+    pub third: Option<NeowContent>,
+    pub boss_swap: Relic,
+}
+
+struct Resolver<'a> {
+    seed: &'a Seed,
+    cards: CardRewarder<'static, 1>,
+    transformer: Transformer,
+    relics: RelicPools,
+}
+
+impl<'a> Resolver<'a> {
+    // every option continues the Neow stream from where the options were rolled
+    fn neow_rng(&self) -> Random {
+        let mut rng = Stream::Neow.rng(self.seed);
+        rng.advance(OPTION_ROLLS);
+        rng
+    }
+
+    fn relic(&self, tier: Tier) -> Option<NeowContent> {
+        self.relics.first(tier).map(NeowContent::Relic)
+    }
+
+    // `getRewardCards`, which rolls a rarity even when it only offers rares
+    fn card_choice(&self, rare_only: bool) -> NeowContent {
+        let choice = self.cards.generate_reward(
+            &mut self.neow_rng(),
+            &mut Offset::default(),
+            RewardContext::Event { rare_only },
+        );
+        NeowContent::CardChoice(choice.map(|reward| reward.card))
+    }
+
+    // `getColorlessRewardCards`, whose cards come from the card stream
+    fn colorless_choice(&self, rare_only: bool) -> NeowContent {
+        let mut rng = self.neow_rng();
+        let mut card_rng = Stream::Card.rng(self.seed);
+        let colorless = ColorlessCards::new();
+        let pool = if rare_only {
+            colorless.rare.slice
+        } else {
+            colorless.uncommon.slice
+        };
+        let mut choice = [Card::Invalid; 3];
+        for i in 0..choice.len() {
+            // commons are bumped to uncommon, there are no colorless commons
+            random_boolean(&mut rng, 0.33);
+            let mut card = pool[card_rng.next_capped_u64(pool.len() as u64) as usize];
+            while choice[..i].contains(&card) {
+                card = pool[card_rng.next_capped_u64(pool.len() as u64) as usize];
+            }
+            choice[i] = card;
+        }
+        NeowContent::CardChoice(choice)
+    }
+
+    fn transform(&self) -> [Card; 2] {
+        let cards = self.transformer.transform(self.seed, &[Card::Invalid; 2]);
+        let Some([first, second]) = cards.as_ref().map(TransformedCards::as_slice) else {
+            unreachable!()
+        };
+        [*first, *second]
+    }
+}
+
+impl NeowContents {
+    pub fn generate(
+        bonuses: &NeowBonuses,
+        seed: &Seed,
+        character: Character,
+        unlocks: Option<Unlocks>,
+    ) -> Self {
+        let resolver = Resolver {
+            seed,
+            cards: CardRewarder::new(character, unlocks),
+            transformer: Transformer::new(character, unlocks, TransformSource::Neow),
+            relics: RelicPools::new(character, seed),
+        };
+        let first = match bonuses.first {
+            FirstBonus::ThreeCards => Some(resolver.card_choice(false)),
+            FirstBonus::OneRandomRareCard => {
+                let mut rng = resolver.neow_rng();
+                let card = resolver.cards.generate_card(&mut rng, Rarity::Rare);
+                Some(NeowContent::Card(card))
+            }
+            FirstBonus::RandomColorless => Some(resolver.colorless_choice(false)),
+            FirstBonus::TransformCard => Some(NeowContent::Card(resolver.transform()[0])),
+            FirstBonus::RemoveCard | FirstBonus::UpgradeCard => None,
+        };
+        let second = match bonuses.second {
+            SecondBonus::RandomCommonRelic => resolver.relic(Tier::Common),
+            _ => None,
+        };
+        let third = match bonuses.third.1 {
+            ThirdBonus::RandomColorlessChoice => Some(resolver.colorless_choice(true)),
+            ThirdBonus::OneRareRelic => resolver.relic(Tier::Rare),
+            ThirdBonus::ThreeRareCards => Some(resolver.card_choice(true)),
+            ThirdBonus::TransformTwoCards => Some(NeowContent::Cards(resolver.transform())),
+            _ => None,
+        };
+        let Some(boss_swap) = resolver.relics.first(Tier::Boss) else {
+            unreachable!()
+        };
+        Self {
+            first,
+            second,
+            third,
+            boss_swap,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod neow_contents_tests {
+    use super::*;
+    use crate::card::transform::TransformPool;
+    use crate::neow::Drawback;
+
+    fn options(first: FirstBonus, second: SecondBonus, third: ThirdBonus) -> NeowBonuses {
+        NeowBonuses {
+            first,
+            second,
+            third: (Drawback::TenPercentHpLoss, third),
+        }
+    }
+
+    #[test]
+    fn test_relics() {
+        let seed = Seed::from(b"    1J432TK4I");
+        let bonuses = options(
+            FirstBonus::RemoveCard,
+            SecondBonus::RandomCommonRelic,
+            ThirdBonus::OneRareRelic,
+        );
+        let contents = NeowContents::generate(&bonuses, &seed, Character::Ironclad, None);
+        assert_eq!(contents.first, None);
+        assert_eq!(contents.second, Some(NeowContent::Relic(Relic::MawBank)));
+        assert_eq!(
+            contents.third,
+            Some(NeowContent::Relic(Relic::GamblingChip))
+        );
+        assert_eq!(contents.boss_swap, Relic::RunicDome);
+    }
+
+    #[test]
+    fn test_card_choices() {
+        let rewarder: CardRewarder<'_, 1> = CardRewarder::new(Character::Silent, None);
+        let colorless = ColorlessCards::new();
+        for seed in 0i64..50 {
+            let seed = Seed::from(seed);
+            let bonuses = options(
+                FirstBonus::ThreeCards,
+                SecondBonus::HundredGold,
+                ThirdBonus::ThreeRareCards,
+            );
+            let contents = NeowContents::generate(&bonuses, &seed, Character::Silent, None);
+            let Some(NeowContent::CardChoice(rares)) = contents.third else {
+                panic!("{contents:?}")
+            };
+            assert!(rares
+                .iter()
+                .all(|card| rewarder.card_pool(Rarity::Rare).contains(card)));
+            let Some(NeowContent::CardChoice(cards)) = contents.first else {
+                panic!("{contents:?}")
+            };
+            assert!(cards
+                .iter()
+                .all(|card| !rewarder.card_pool(Rarity::Rare).contains(card)));
+            for choice in [rares, cards] {
+                assert!(choice[0] != choice[1] && choice[1] != choice[2] && choice[0] != choice[2]);
+            }
+
+            let bonuses = options(
+                FirstBonus::RandomColorless,
+                SecondBonus::HundredGold,
+                ThirdBonus::RandomColorlessChoice,
+            );
+            let contents = NeowContents::generate(&bonuses, &seed, Character::Silent, None);
+            let Some(NeowContent::CardChoice(uncommons)) = contents.first else {
+                panic!("{contents:?}")
+            };
+            assert!(uncommons
+                .iter()
+                .all(|card| colorless.uncommon.slice.contains(card)));
+            let Some(NeowContent::CardChoice(rares)) = contents.third else {
+                panic!("{contents:?}")
+            };
+            assert!(rares.iter().all(|card| colorless.rare.slice.contains(card)));
+        }
+    }
+
+    #[test]
+    fn test_transforms() {
+        let seed = Seed::from(3i64);
+        let bonuses = options(
+            FirstBonus::TransformCard,
+            SecondBonus::HundredGold,
+            ThirdBonus::TransformTwoCards,
+        );
+        let contents = NeowContents::generate(&bonuses, &seed, Character::Watcher, None);
+        let Some(NeowContent::Cards(cards)) = contents.third else {
+            panic!("{contents:?}")
+        };
+        // both options draw from the same point of the Neow stream
+        assert_eq!(contents.first, Some(NeowContent::Card(cards[0])));
+        let pool = TransformPool::new(Character::Watcher, None);
+        assert!(cards.iter().all(|card| pool.as_slice().contains(card)));
+    }
+}
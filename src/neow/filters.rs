@@ -0,0 +1,208 @@
+use libgdx_xs128::rng::Random;
+
+use crate::{filter::RngFilter, rng::Stream, seed::Seed};
+
+use super::{Blessing, Drawback, FirstBonus, NeowOptions, SecondBonus, ThirdBonus};
+
+// what an option does to the run, across the three bonus enums
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Effect {
+    RemoveCards,
+    TransformCards,
+    UpgradeCard,
+    Cards,
+    RareCards,
+    ColorlessCards,
+    Relic,
+    Potions,
+    Gold,
+    MaxHp,
+    EnemyKill,
+}
+
+impl FirstBonus {
+    pub const fn effect(self) -> Effect {
+        match self {
+            FirstBonus::ThreeCards => Effect::Cards,
+            FirstBonus::OneRandomRareCard => Effect::RareCards,
+            FirstBonus::RemoveCard => Effect::RemoveCards,
+            FirstBonus::UpgradeCard => Effect::UpgradeCard,
+            FirstBonus::TransformCard => Effect::TransformCards,
+            FirstBonus::RandomColorless => Effect::ColorlessCards,
+        }
+    }
+}
+
+impl SecondBonus {
+    pub const fn effect(self) -> Effect {
+        match self {
+            SecondBonus::ThreeSmallPotions => Effect::Potions,
+            SecondBonus::RandomCommonRelic => Effect::Relic,
+            SecondBonus::TenPercentHpBonus => Effect::MaxHp,
+            SecondBonus::ThreeEnemyKill => Effect::EnemyKill,
+            SecondBonus::HundredGold => Effect::Gold,
+        }
+    }
+}
+
+impl ThirdBonus {
+    pub const fn effect(self) -> Effect {
+        match self {
+            ThirdBonus::RandomColorlessChoice => Effect::ColorlessCards,
+            ThirdBonus::RemoveTwo => Effect::RemoveCards,
+            ThirdBonus::OneRareRelic => Effect::Relic,
+            ThirdBonus::ThreeRareCards => Effect::RareCards,
+            ThirdBonus::TwoFiftyGold => Effect::Gold,
+            ThirdBonus::TransformTwoCards => Effect::TransformCards,
+            ThirdBonus::TwentyPercentHpBonus => Effect::MaxHp,
+        }
+    }
+}
+
+// a mini blessing only has second-slot options, so patterns on the other slots fail it
+pub enum NeowPattern<'a> {
+    First(&'a [FirstBonus]),
+    Second(&'a [SecondBonus]),
+    Drawback(&'a [Drawback]),
+    Third(&'a [ThirdBonus]),
+    Pair(Drawback, ThirdBonus),
+    Offers(Effect),
+    All(&'a [NeowPattern<'a>]),
+    Any(&'a [NeowPattern<'a>]),
+    Not(&'a NeowPattern<'a>),
+}
+
+impl<'a> NeowPattern<'a> {
+    pub fn matches(&self, options: &NeowOptions) -> bool {
+        match (self, options) {
+            (NeowPattern::All(patterns), _) => patterns.iter().all(|p| p.matches(options)),
+            (NeowPattern::Any(patterns), _) => patterns.iter().any(|p| p.matches(options)),
+            (NeowPattern::Not(pattern), _) => !pattern.matches(options),
+            (NeowPattern::First(firsts), NeowOptions::Full(bonuses)) => {
+                firsts.contains(&bonuses.first)
+            }
+            (NeowPattern::Second(seconds), NeowOptions::Full(bonuses)) => {
+                seconds.contains(&bonuses.second)
+            }
+            (NeowPattern::Second(seconds), NeowOptions::Mini(mini)) => {
+                mini.iter().any(|option| seconds.contains(option))
+            }
+            (NeowPattern::Drawback(drawbacks), NeowOptions::Full(bonuses)) => {
+                drawbacks.contains(&bonuses.third.0)
+            }
+            (NeowPattern::Third(thirds), NeowOptions::Full(bonuses)) => {
+                thirds.contains(&bonuses.third.1)
+            }
+            (NeowPattern::Pair(drawback, third), NeowOptions::Full(bonuses)) => {
+                bonuses.third == (*drawback, *third)
+            }
+            (NeowPattern::Offers(effect), NeowOptions::Full(bonuses)) => {
+                bonuses.first.effect() == *effect
+                    || bonuses.second.effect() == *effect
+                    || bonuses.third.1.effect() == *effect
+            }
+            (NeowPattern::Offers(effect), NeowOptions::Mini(mini)) => {
+                mini.iter().any(|option| option.effect() == *effect)
+            }
+            (_, NeowOptions::Mini(_)) => false,
+        }
+    }
+}
+
+pub struct NeowFilter<'a> {
+    pattern: NeowPattern<'a>,
+    blessing: Blessing,
+}
+
+impl<'a> NeowFilter<'a> {
+    pub const fn new(pattern: NeowPattern<'a>) -> Self {
+        Self {
+            pattern,
+            blessing: Blessing::Full,
+        }
+    }
+
+    pub const fn with_blessing(self, blessing: Blessing) -> Self {
+        Self { blessing, ..self }
+    }
+}
+
+impl<'a> RngFilter for NeowFilter<'a> {
+    fn reject_rng(&self, rng: &mut Random) -> bool {
+        !self
+            .pattern
+            .matches(&NeowOptions::generate_rng(self.blessing, rng))
+    }
+
+    fn rng(&self, seed: &Seed) -> Random {
+        Stream::Neow.rng(seed)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod neow_filter_tests {
+    use libgdx_xs128::RandomXS128;
+
+    use super::*;
+    use crate::{filter::SeedFilter, neow::NeowBonuses};
+
+    #[test]
+    fn test_pair() {
+        const FILTER: NeowFilter<'_> = NeowFilter::new(NeowPattern::Pair(
+            Drawback::NoGold,
+            ThirdBonus::OneRareRelic,
+        ));
+        for seed in 1u64..200 {
+            let bonuses = NeowBonuses::generate(Random::new(seed));
+            let expected = bonuses.third == (Drawback::NoGold, ThirdBonus::OneRareRelic);
+            assert_eq!(FILTER.reject(seed), !expected, "{seed}");
+        }
+        assert!((1u64..200).any(|seed| !FILTER.reject(seed)));
+    }
+
+    #[test]
+    fn test_removes_cards() {
+        const FILTER: NeowFilter<'_> = NeowFilter::new(NeowPattern::Any(&[
+            NeowPattern::First(&[FirstBonus::RemoveCard]),
+            NeowPattern::Third(&[ThirdBonus::RemoveTwo]),
+        ]));
+        const EFFECT: NeowFilter<'_> = NeowFilter::new(NeowPattern::Offers(Effect::RemoveCards));
+        for seed in 1u64..200 {
+            assert_eq!(FILTER.reject(seed), EFFECT.reject(seed), "{seed}");
+        }
+    }
+
+    #[test]
+    fn test_combinations() {
+        const RELICS: [NeowPattern<'_>; 2] = [
+            NeowPattern::Second(&[SecondBonus::RandomCommonRelic]),
+            NeowPattern::Not(&NeowPattern::Drawback(&[Drawback::Curse])),
+        ];
+        const FILTER: NeowFilter<'_> = NeowFilter::new(NeowPattern::All(&RELICS));
+        for seed in 1u64..200 {
+            let bonuses = NeowBonuses::generate(Random::new(seed));
+            let expected = bonuses.second == SecondBonus::RandomCommonRelic
+                && bonuses.third.0 != Drawback::Curse;
+            assert_eq!(FILTER.reject(seed), !expected, "{seed}");
+        }
+    }
+
+    #[test]
+    fn test_mini_blessing() {
+        const KILL: NeowFilter<'_> =
+            NeowFilter::new(NeowPattern::Offers(Effect::EnemyKill)).with_blessing(Blessing::Mini);
+        const GOLD: NeowFilter<'_> =
+            NeowFilter::new(NeowPattern::Offers(Effect::Gold)).with_blessing(Blessing::Mini);
+        const NOT_FIRST: NeowFilter<'_> =
+            NeowFilter::new(NeowPattern::Not(&NeowPattern::First(&[
+                FirstBonus::ThreeCards,
+            ])))
+            .with_blessing(Blessing::Mini);
+        for seed in 1u64..50 {
+            assert!(!KILL.reject(seed));
+            assert!(GOLD.reject(seed));
+            assert!(!NOT_FIRST.reject(seed));
+        }
+    }
+}
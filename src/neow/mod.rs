@@ -1,14 +1,56 @@
+pub mod contents;
+pub mod filters;
+
 use libgdx_xs128::rng::Random;
 use libgdx_xs128::RandomXS128;
 
-#[derive(Debug, PartialEq, Eq)]
+// Neow offers only a mini blessing when the previous run did not reach the first boss
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Blessing {
+    #[default]
+    Full,
+    Mini,
+}
+
+impl Blessing {
+    pub const fn new(reached_first_boss: bool) -> Self {
+        if reached_first_boss {
+            Blessing::Full
+        } else {
+            Blessing::Mini
+        }
+    }
+}
+
+// the mini blessing's two options are fixed and roll nothing
+pub const MINI_BLESSING: [SecondBonus; 2] =
+    [SecondBonus::ThreeEnemyKill, SecondBonus::TenPercentHpBonus];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NeowOptions {
+    Full(NeowBonuses),
+    Mini([SecondBonus; 2]),
+}
+
+impl NeowOptions {
+    pub fn generate_rng(blessing: Blessing, rng: &mut Random) -> Self {
+        match blessing {
+            Blessing::Full => NeowOptions::Full(NeowBonuses::generate_rng(rng)),
+            Blessing::Mini => NeowOptions::Mini(MINI_BLESSING),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct NeowBonuses {
     pub first: FirstBonus,
     pub second: SecondBonus,
     pub third: (Drawback, ThirdBonus),
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum FirstBonus {
     ThreeCards,
     OneRandomRareCard,
@@ -18,7 +60,8 @@ pub enum FirstBonus {
     RandomColorless,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SecondBonus {
     ThreeSmallPotions,
     RandomCommonRelic,
@@ -27,7 +70,8 @@ pub enum SecondBonus {
     HundredGold,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum ThirdBonus {
     RandomColorlessChoice,
     RemoveTwo,
@@ -38,7 +82,8 @@ pub enum ThirdBonus {
     TwentyPercentHpBonus,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Drawback {
     TenPercentHpLoss,
     NoGold,
@@ -48,6 +93,10 @@ pub enum Drawback {
 
 impl NeowBonuses {
     pub fn generate(mut rng: Random) -> Self {
+        Self::generate_rng(&mut rng)
+    }
+
+    pub fn generate_rng(rng: &mut Random) -> Self {
         let first = match rng.next_capped_u64(6) {
             0 => FirstBonus::ThreeCards,
             1 => FirstBonus::OneRandomRareCard,
@@ -124,3 +173,27 @@ impl NeowBonuses {
         }
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod neow_options_tests {
+    use libgdx_xs128::rng::Random;
+
+    use super::*;
+
+    #[test]
+    fn test_blessings() {
+        assert_eq!(Blessing::new(true), Blessing::Full);
+        assert_eq!(Blessing::new(false), Blessing::Mini);
+        for seed in 1u64..20 {
+            let full = NeowOptions::generate_rng(Blessing::Full, &mut Random::new(seed));
+            let bonuses = NeowBonuses::generate(Random::new(seed));
+            assert_eq!(full, NeowOptions::Full(bonuses));
+
+            let mut rng = Random::new(seed);
+            let mini = NeowOptions::generate_rng(Blessing::Mini, &mut rng);
+            assert_eq!(mini, NeowOptions::Mini(MINI_BLESSING));
+            assert_eq!(rng.next_u64(), Random::new(seed).next_u64());
+        }
+    }
+}
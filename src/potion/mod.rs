@@ -0,0 +1,231 @@
+use libgdx_xs128::{rng::Random, RandomXS128};
+use strum::EnumCount;
+use strum_macros::EnumCount;
+use strum_macros::EnumIter;
+use strum_macros::FromRepr;
+
+use crate::{card::Rarity, character::Character};
+
+// each character's three potions, then the shared ones in `PotionHelper.getPotions` order
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, FromRepr, EnumIter, EnumCount, Clone, Copy)]
+pub enum Potion {
+    // Ironclad
+    BloodPotion,
+    Elixir,
+    HeartOfIron,
+    // Silent
+    PoisonPotion,
+    CunningPotion,
+    GhostInAJar,
+    // Defect
+    FocusPotion,
+    PotionOfCapacity,
+    EssenceOfDarkness,
+    // Watcher
+    BottledMiracle,
+    StancePotion,
+    Ambrosia,
+    // Shared
+    BlockPotion,
+    DexterityPotion,
+    EnergyPotion,
+    ExplosivePotion,
+    FirePotion,
+    StrengthPotion,
+    SwiftPotion,
+    WeakPotion,
+    FearPotion,
+    AttackPotion,
+    SkillPotion,
+    PowerPotion,
+    ColorlessPotion,
+    FlexPotion,
+    SpeedPotion,
+    BlessingOfTheForge,
+    RegenPotion,
+    AncientPotion,
+    LiquidBronze,
+    GamblersBrew,
+    EssenceOfSteel,
+    DuplicationPotion,
+    DistilledChaos,
+    LiquidMemories,
+    CultistPotion,
+    FruitJuice,
+    SneckoOil,
+    FairyInABottle,
+    SmokeBomb,
+    EntropicBrew,
+}
+
+impl Potion {
+    pub const fn id(self) -> &'static str {
+        use Potion::*;
+        match self {
+            BloodPotion => "BloodPotion",
+            Elixir => "ElixirPotion",
+            HeartOfIron => "HeartOfIron",
+            PoisonPotion => "Poison Potion",
+            CunningPotion => "CunningPotion",
+            GhostInAJar => "GhostInAJar",
+            FocusPotion => "FocusPotion",
+            PotionOfCapacity => "PotionOfCapacity",
+            EssenceOfDarkness => "EssenceOfDarkness",
+            BottledMiracle => "BottledMiracle",
+            StancePotion => "StancePotion",
+            Ambrosia => "Ambrosia",
+            BlockPotion => "Block Potion",
+            DexterityPotion => "Dexterity Potion",
+            EnergyPotion => "Energy Potion",
+            ExplosivePotion => "Explosive Potion",
+            FirePotion => "Fire Potion",
+            StrengthPotion => "Strength Potion",
+            SwiftPotion => "Swift Potion",
+            WeakPotion => "Weak Potion",
+            FearPotion => "FearPotion",
+            AttackPotion => "AttackPotion",
+            SkillPotion => "SkillPotion",
+            PowerPotion => "PowerPotion",
+            ColorlessPotion => "ColorlessPotion",
+            FlexPotion => "SteroidPotion",
+            SpeedPotion => "SpeedPotion",
+            BlessingOfTheForge => "BlessingOfTheForge",
+            RegenPotion => "Regen Potion",
+            AncientPotion => "Ancient Potion",
+            LiquidBronze => "LiquidBronze",
+            GamblersBrew => "GamblersBrew",
+            EssenceOfSteel => "EssenceOfSteel",
+            DuplicationPotion => "DuplicationPotion",
+            DistilledChaos => "DistilledChaos",
+            LiquidMemories => "LiquidMemories",
+            CultistPotion => "CultistPotion",
+            FruitJuice => "Fruit Juice",
+            SneckoOil => "SneckoOil",
+            FairyInABottle => "FairyPotion",
+            SmokeBomb => "SmokeBomb",
+            EntropicBrew => "EntropicBrew",
+        }
+    }
+
+    pub const fn rarity(self) -> Rarity {
+        use Potion::*;
+        match self {
+            BloodPotion | PoisonPotion | FocusPotion | BottledMiracle | BlockPotion
+            | DexterityPotion | EnergyPotion | ExplosivePotion | FirePotion | StrengthPotion
+            | SwiftPotion | WeakPotion | FearPotion | AttackPotion | SkillPotion | PowerPotion
+            | ColorlessPotion | FlexPotion | SpeedPotion | BlessingOfTheForge => Rarity::Common,
+            Elixir | CunningPotion | PotionOfCapacity | StancePotion | RegenPotion
+            | AncientPotion | LiquidBronze | GamblersBrew | EssenceOfSteel | DuplicationPotion
+            | DistilledChaos | LiquidMemories => Rarity::Uncommon,
+            HeartOfIron | GhostInAJar | EssenceOfDarkness | Ambrosia | CultistPotion
+            | FruitJuice | SneckoOil | FairyInABottle | SmokeBomb | EntropicBrew => Rarity::Rare,
+        }
+    }
+
+    pub const fn character(self) -> Option<Character> {
+        match self as usize / CHARACTER_POTIONS {
+            0 => Some(Character::Ironclad),
+            1 => Some(Character::Silent),
+            2 => Some(Character::Defect),
+            3 => Some(Character::Watcher),
+            _ => None,
+        }
+    }
+
+    // `AbstractPotion.getPrice`, before the merchant's roll
+    pub const fn price(self) -> u32 {
+        match self.rarity() {
+            Rarity::Common => 50,
+            Rarity::Uncommon => 75,
+            Rarity::Rare => 100,
+        }
+    }
+}
+
+const CHARACTER_POTIONS: usize = 3;
+const SHARED_POTIONS: usize = Potion::COUNT - CHARACTER_POTIONS * Character::COUNT;
+pub const POOL_LEN: usize = CHARACTER_POTIONS + SHARED_POTIONS;
+
+// the potions a character can find, in the order `PotionHelper.getRandomPotion` indexes
+pub struct PotionPool {
+    potions: [Potion; POOL_LEN],
+}
+
+impl PotionPool {
+    pub const fn new(character: Character) -> Self {
+        let mut potions = [Potion::BlockPotion; POOL_LEN];
+        let own = character as usize * CHARACTER_POTIONS;
+        let shared = Potion::COUNT - SHARED_POTIONS;
+        let mut i = 0;
+        while i < POOL_LEN {
+            let index = if i < CHARACTER_POTIONS {
+                own + i
+            } else {
+                shared + i - CHARACTER_POTIONS
+            };
+            let Some(potion) = Potion::from_repr(index) else {
+                unreachable!()
+            };
+            potions[i] = potion;
+            i += 1;
+        }
+        Self { potions }
+    }
+
+    pub fn as_slice(&self) -> &[Potion] {
+        &self.potions
+    }
+
+    // `returnRandomPotion`: a rarity roll, then random potions until one has that rarity
+    pub fn generate(&self, rng: &mut Random) -> Potion {
+        let rarity = match rng.next_capped_u64(100) {
+            0..=64 => Rarity::Common,
+            65..=89 => Rarity::Uncommon,
+            _ => Rarity::Rare,
+        };
+        loop {
+            let potion = self.potions[rng.next_capped_u64(POOL_LEN as u64) as usize];
+            if potion.rarity() == rarity {
+                return potion;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod potion_tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_pools() {
+        for character in Character::iter() {
+            let pool = PotionPool::new(character);
+            assert!(pool
+                .as_slice()
+                .iter()
+                .all(|potion| potion.character().is_none_or(|other| other == character)));
+            assert_eq!(
+                pool.as_slice()
+                    .iter()
+                    .filter(|potion| potion.character().is_some())
+                    .count(),
+                CHARACTER_POTIONS
+            );
+        }
+    }
+
+    #[test]
+    fn test_rarities() {
+        let pool = PotionPool::new(Character::Defect);
+        let mut rng = Random::new(7);
+        let mut counts = [0; 3];
+        for _ in 0..1000 {
+            counts[pool.generate(&mut rng).rarity() as usize] += 1;
+        }
+        assert!(counts[0] > counts[1] && counts[1] > counts[2], "{counts:?}");
+    }
+}
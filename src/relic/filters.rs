@@ -0,0 +1,92 @@
+use libgdx_xs128::rng::Random;
+
+use crate::{character::Character, filter::RngFilter, rng::Stream, seed::Seed};
+
+use super::{
+    pool::{RelicPools, BOSS_OFFER_LEN},
+    Relic,
+};
+
+// the Act I boss relic offer holds every required relic and none of the rejected ones
+pub struct BossRelicFilter<'a> {
+    character: Character,
+    required: &'a [Relic],
+    rejected: &'a [Relic],
+}
+
+impl<'a> BossRelicFilter<'a> {
+    pub const fn new(character: Character, required: &'a [Relic], rejected: &'a [Relic]) -> Self {
+        Self {
+            character,
+            required,
+            rejected,
+        }
+    }
+}
+
+impl<'a> RngFilter for BossRelicFilter<'a> {
+    fn reject_rng(&self, rng: &mut Random) -> bool {
+        let pool = RelicPools::generate_boss(self.character, rng);
+        let offer = &pool.as_slice()[..BOSS_OFFER_LEN];
+        !self.required.iter().all(|relic| offer.contains(relic))
+            || offer.iter().any(|relic| self.rejected.contains(relic))
+    }
+
+    fn rng(&self, seed: &Seed) -> Random {
+        Stream::Relic.rng(seed)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod boss_relic_tests {
+    use crate::{
+        character::Character,
+        filter::SeedFilter,
+        relic::{filters::BossRelicFilter, Relic},
+        seed::Seed,
+    };
+
+    // offers Runic Dome, Coffee Dripper and Astrolabe to the Ironclad
+    const SEED: &[u8; 13] = b"    1J432TK4I";
+
+    #[test]
+    fn test_required_relics() {
+        const DOME: BossRelicFilter<'_> =
+            BossRelicFilter::new(Character::Ironclad, &[Relic::RunicDome], &[]);
+        const ENERGY: BossRelicFilter<'_> = BossRelicFilter::new(
+            Character::Ironclad,
+            &[Relic::RunicDome, Relic::CoffeeDripper],
+            &[],
+        );
+        const SNECKO: BossRelicFilter<'_> =
+            BossRelicFilter::new(Character::Ironclad, &[Relic::SneckoEye], &[]);
+        assert!(!DOME.reject(Seed::from(SEED)));
+        assert!(!ENERGY.reject(Seed::from(SEED)));
+        assert!(SNECKO.reject(Seed::from(SEED)));
+    }
+
+    #[test]
+    fn test_rejected_relics() {
+        const NO_ASTROLABE: BossRelicFilter<'_> =
+            BossRelicFilter::new(Character::Ironclad, &[], &[Relic::Astrolabe]);
+        const NO_PYRAMID: BossRelicFilter<'_> =
+            BossRelicFilter::new(Character::Ironclad, &[], &[Relic::RunicPyramid]);
+        assert!(NO_ASTROLABE.reject(Seed::from(SEED)));
+        assert!(!NO_PYRAMID.reject(Seed::from(SEED)));
+    }
+
+    #[test]
+    fn test_character_pools() {
+        // the Watcher's pool shuffles to a different offer
+        const DOME: BossRelicFilter<'_> =
+            BossRelicFilter::new(Character::Watcher, &[Relic::RunicDome], &[]);
+        const CROWN: BossRelicFilter<'_> = BossRelicFilter::new(
+            Character::Watcher,
+            &[Relic::BustedCrown, Relic::HolyWater],
+            &[],
+        );
+        assert!(DOME.reject(Seed::from(SEED)));
+        assert!(!CROWN.reject(Seed::from(SEED)));
+    }
+}
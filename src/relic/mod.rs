@@ -0,0 +1,485 @@
+pub mod filters;
+pub mod pool;
+
+use strum_macros::EnumCount;
+use strum_macros::EnumIter;
+use strum_macros::FromRepr;
+
+use crate::character::Character;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr, EnumIter, EnumCount)]
+pub enum Tier {
+    Starter,
+    Common,
+    Uncommon,
+    Rare,
+    Shop,
+    Boss,
+    // event and other relics never offered from a pool
+    Special,
+}
+
+// in the order `RelicLibrary` registers them, which breaks ties in its hash maps
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Eq, PartialEq, FromRepr, EnumIter, EnumCount, Clone, Copy)]
+pub enum Relic {
+    // Shared
+    Abacus,
+    Akabeko,
+    Anchor,
+    AncientTeaSet,
+    ArtOfWar,
+    Astrolabe,
+    BagOfMarbles,
+    BagOfPreparation,
+    BirdFacedUrn,
+    BlackStar,
+    BloodVial,
+    BloodyIdol,
+    BlueCandle,
+    Boot,
+    BottledFlame,
+    BottledLightning,
+    BottledTornado,
+    BronzeScales,
+    BustedCrown,
+    Calipers,
+    CallingBell,
+    CaptainsWheel,
+    Cauldron,
+    CentennialPuzzle,
+    CeramicFish,
+    ChemicalX,
+    Circlet,
+    ClockworkSouvenir,
+    CoffeeDripper,
+    Courier,
+    CultistMask,
+    CursedKey,
+    DarkstonePeriapt,
+    DeadBranch,
+    DollysMirror,
+    DreamCatcher,
+    DuVuDoll,
+    Ectoplasm,
+    EmptyCage,
+    Enchiridion,
+    EternalFeather,
+    FaceOfCleric,
+    FossilizedHelix,
+    FrozenEgg,
+    FrozenEye,
+    FusionHammer,
+    GamblingChip,
+    Ginger,
+    Girya,
+    GoldenIdol,
+    GremlinHorn,
+    GremlinVisage,
+    HandDrill,
+    HappyFlower,
+    HornCleat,
+    IceCream,
+    IncenseBurner,
+    InkBottle,
+    JuzuBracelet,
+    Kunai,
+    Lantern,
+    LetterOpener,
+    LizardTail,
+    Mango,
+    MarkOfTheBloom,
+    Matryoshka,
+    MawBank,
+    MealTicket,
+    MeatOnTheBone,
+    MedicalKit,
+    MembershipCard,
+    MercuryHourglass,
+    MoltenEgg,
+    MummifiedHand,
+    MutagenicStrength,
+    Necronomicon,
+    NeowsLament,
+    NilrysCodex,
+    NlothsGift,
+    NlothsHungryFace,
+    Nunchaku,
+    OddMushroom,
+    OddlySmoothStone,
+    OldCoin,
+    Omamori,
+    OrangePellets,
+    Orichalcum,
+    OrnamentalFan,
+    Orrery,
+    PandorasBox,
+    Pantograph,
+    PeacePipe,
+    Pear,
+    PenNib,
+    PhilosophersStone,
+    Pocketwatch,
+    PotionBelt,
+    PrayerWheel,
+    PreservedInsect,
+    PrismaticShard,
+    QuestionCard,
+    RedCirclet,
+    RedMask,
+    RegalPillow,
+    RunicDome,
+    RunicPyramid,
+    SacredBark,
+    Shovel,
+    Shuriken,
+    SingingBowl,
+    SlaversCollar,
+    SlingOfCourage,
+    SmilingMask,
+    SneckoEye,
+    Sozu,
+    SpiritPoop,
+    SsserpentHead,
+    StoneCalendar,
+    StrangeSpoon,
+    Strawberry,
+    StrikeDummy,
+    Sundial,
+    ThreadAndNeedle,
+    TinyChest,
+    TinyHouse,
+    Toolbox,
+    Torii,
+    ToxicEgg,
+    ToyOrnithopter,
+    TungstenRod,
+    Turnip,
+    UnceasingTop,
+    Vajra,
+    VelvetChoker,
+    LeesWaffle,
+    WarPaint,
+    WarpedTongs,
+    Whetstone,
+    WhiteBeastStatue,
+    WingBoots,
+    // Ironclad
+    BlackBlood,
+    Brimstone,
+    BurningBlood,
+    ChampionBelt,
+    CharonsAshes,
+    MagicFlower,
+    MarkOfPain,
+    PaperPhrog,
+    RedSkull,
+    RunicCube,
+    SelfFormingClay,
+    // Silent
+    HoveringKite,
+    NinjaScroll,
+    PaperKrane,
+    RingOfTheSerpent,
+    RingOfTheSnake,
+    SnakeSkull,
+    TheSpecimen,
+    Tingsha,
+    ToughBandages,
+    TwistedFunnel,
+    WristBlade,
+    // Defect
+    CrackedCore,
+    DataDisk,
+    EmotionChip,
+    FrozenCore,
+    GoldPlatedCables,
+    Inserter,
+    NuclearBattery,
+    RunicCapacitor,
+    SymbioticVirus,
+    // Watcher
+    CloakClasp,
+    Damaru,
+    GoldenEye,
+    HolyWater,
+    Melange,
+    PureWater,
+    VioletLotus,
+    TeardropLocket,
+    Duality,
+}
+
+impl Relic {
+    // the game's relic id, whose `String.hashCode` orders the unshuffled pools
+    pub const fn id(self) -> &'static str {
+        use Relic::*;
+        match self {
+            Abacus => "TheAbacus",
+            Akabeko => "Akabeko",
+            Anchor => "Anchor",
+            AncientTeaSet => "Ancient Tea Set",
+            ArtOfWar => "Art of War",
+            Astrolabe => "Astrolabe",
+            BagOfMarbles => "Bag of Marbles",
+            BagOfPreparation => "Bag of Preparation",
+            BirdFacedUrn => "Bird Faced Urn",
+            BlackStar => "Black Star",
+            BloodVial => "Blood Vial",
+            BloodyIdol => "Bloody Idol",
+            BlueCandle => "Blue Candle",
+            Boot => "Boot",
+            BottledFlame => "Bottled Flame",
+            BottledLightning => "Bottled Lightning",
+            BottledTornado => "Bottled Tornado",
+            BronzeScales => "Bronze Scales",
+            BustedCrown => "Busted Crown",
+            Calipers => "Calipers",
+            CallingBell => "Calling Bell",
+            CaptainsWheel => "CaptainsWheel",
+            Cauldron => "Cauldron",
+            CentennialPuzzle => "Centennial Puzzle",
+            CeramicFish => "CeramicFish",
+            ChemicalX => "Chemical X",
+            Circlet => "Circlet",
+            ClockworkSouvenir => "ClockworkSouvenir",
+            CoffeeDripper => "Coffee Dripper",
+            Courier => "The Courier",
+            CultistMask => "CultistMask",
+            CursedKey => "Cursed Key",
+            DarkstonePeriapt => "Darkstone Periapt",
+            DeadBranch => "Dead Branch",
+            DollysMirror => "DollysMirror",
+            DreamCatcher => "Dream Catcher",
+            DuVuDoll => "Du-Vu Doll",
+            Ectoplasm => "Ectoplasm",
+            EmptyCage => "Empty Cage",
+            Enchiridion => "Enchiridion",
+            EternalFeather => "Eternal Feather",
+            FaceOfCleric => "FaceOfCleric",
+            FossilizedHelix => "FossilizedHelix",
+            FrozenEgg => "Frozen Egg 2",
+            FrozenEye => "Frozen Eye",
+            FusionHammer => "Fusion Hammer",
+            GamblingChip => "Gambling Chip",
+            Ginger => "Ginger",
+            Girya => "Girya",
+            GoldenIdol => "Golden Idol",
+            GremlinHorn => "Gremlin Horn",
+            GremlinVisage => "GremlinMask",
+            HandDrill => "HandDrill",
+            HappyFlower => "Happy Flower",
+            HornCleat => "HornCleat",
+            IceCream => "Ice Cream",
+            IncenseBurner => "Incense Burner",
+            InkBottle => "InkBottle",
+            JuzuBracelet => "Juzu Bracelet",
+            Kunai => "Kunai",
+            Lantern => "Lantern",
+            LetterOpener => "Letter Opener",
+            LizardTail => "Lizard Tail",
+            Mango => "Mango",
+            MarkOfTheBloom => "Mark of the Bloom",
+            Matryoshka => "Matryoshka",
+            MawBank => "MawBank",
+            MealTicket => "MealTicket",
+            MeatOnTheBone => "Meat on the Bone",
+            MedicalKit => "Medical Kit",
+            MembershipCard => "Membership Card",
+            MercuryHourglass => "Mercury Hourglass",
+            MoltenEgg => "Molten Egg 2",
+            MummifiedHand => "Mummified Hand",
+            MutagenicStrength => "MutagenicStrength",
+            Necronomicon => "Necronomicon",
+            NeowsLament => "NeowsBlessing",
+            NilrysCodex => "Nilry's Codex",
+            NlothsGift => "Nloth's Gift",
+            NlothsHungryFace => "NlothsMask",
+            Nunchaku => "Nunchaku",
+            OddMushroom => "Odd Mushroom",
+            OddlySmoothStone => "Oddly Smooth Stone",
+            OldCoin => "Old Coin",
+            Omamori => "Omamori",
+            OrangePellets => "OrangePellets",
+            Orichalcum => "Orichalcum",
+            OrnamentalFan => "Ornamental Fan",
+            Orrery => "Orrery",
+            PandorasBox => "Pandora's Box",
+            Pantograph => "Pantograph",
+            PeacePipe => "Peace Pipe",
+            Pear => "Pear",
+            PenNib => "Pen Nib",
+            PhilosophersStone => "Philosopher's Stone",
+            Pocketwatch => "Pocketwatch",
+            PotionBelt => "Potion Belt",
+            PrayerWheel => "Prayer Wheel",
+            PreservedInsect => "PreservedInsect",
+            PrismaticShard => "PrismaticShard",
+            QuestionCard => "Question Card",
+            RedCirclet => "Red Circlet",
+            RedMask => "Red Mask",
+            RegalPillow => "Regal Pillow",
+            RunicDome => "Runic Dome",
+            RunicPyramid => "Runic Pyramid",
+            SacredBark => "SacredBark",
+            Shovel => "Shovel",
+            Shuriken => "Shuriken",
+            SingingBowl => "Singing Bowl",
+            SlaversCollar => "SlaversCollar",
+            SlingOfCourage => "Sling",
+            SmilingMask => "Smiling Mask",
+            SneckoEye => "Snecko Eye",
+            Sozu => "Sozu",
+            SpiritPoop => "Spirit Poop",
+            SsserpentHead => "SsserpentHead",
+            StoneCalendar => "StoneCalendar",
+            StrangeSpoon => "Strange Spoon",
+            Strawberry => "Strawberry",
+            StrikeDummy => "StrikeDummy",
+            Sundial => "Sundial",
+            ThreadAndNeedle => "Thread and Needle",
+            TinyChest => "Tiny Chest",
+            TinyHouse => "Tiny House",
+            Toolbox => "Toolbox",
+            Torii => "Torii",
+            ToxicEgg => "Toxic Egg 2",
+            ToyOrnithopter => "Toy Ornithopter",
+            TungstenRod => "TungstenRod",
+            Turnip => "Turnip",
+            UnceasingTop => "Unceasing Top",
+            Vajra => "Vajra",
+            VelvetChoker => "Velvet Choker",
+            LeesWaffle => "Lee's Waffle",
+            WarPaint => "War Paint",
+            WarpedTongs => "WarpedTongs",
+            Whetstone => "Whetstone",
+            WhiteBeastStatue => "White Beast Statue",
+            WingBoots => "WingedGreaves",
+            BlackBlood => "Black Blood",
+            Brimstone => "Brimstone",
+            BurningBlood => "Burning Blood",
+            ChampionBelt => "Champion Belt",
+            CharonsAshes => "Charon's Ashes",
+            MagicFlower => "Magic Flower",
+            MarkOfPain => "Mark of Pain",
+            PaperPhrog => "Paper Frog",
+            RedSkull => "Red Skull",
+            RunicCube => "Runic Cube",
+            SelfFormingClay => "Self Forming Clay",
+            HoveringKite => "HoveringKite",
+            NinjaScroll => "Ninja Scroll",
+            PaperKrane => "Paper Crane",
+            RingOfTheSerpent => "Ring of the Serpent",
+            RingOfTheSnake => "Ring of the Snake",
+            SnakeSkull => "Snake Skull",
+            TheSpecimen => "The Specimen",
+            Tingsha => "Tingsha",
+            ToughBandages => "Tough Bandages",
+            TwistedFunnel => "Twisted Funnel",
+            WristBlade => "WristBlade",
+            CrackedCore => "Cracked Core",
+            DataDisk => "Data Disk",
+            EmotionChip => "Emotion Chip",
+            FrozenCore => "FrozenCore",
+            GoldPlatedCables => "Cables",
+            Inserter => "Inserter",
+            NuclearBattery => "Nuclear Battery",
+            RunicCapacitor => "Runic Capacitor",
+            SymbioticVirus => "Symbiotic Virus",
+            CloakClasp => "CloakClasp",
+            Damaru => "Damaru",
+            GoldenEye => "GoldenEye",
+            HolyWater => "HolyWater",
+            Melange => "Melange",
+            PureWater => "PureWater",
+            VioletLotus => "VioletLotus",
+            TeardropLocket => "TeardropLocket",
+            Duality => "Yang",
+        }
+    }
+
+    pub const fn tier(self) -> Tier {
+        use Relic::*;
+        use Tier::*;
+        match self {
+            BurningBlood | RingOfTheSnake | CrackedCore | PureWater => Starter,
+            Akabeko | Anchor | AncientTeaSet | ArtOfWar | BagOfMarbles | BagOfPreparation
+            | BloodVial | Boot | BronzeScales | CentennialPuzzle | CeramicFish | DreamCatcher
+            | HappyFlower | JuzuBracelet | Lantern | MawBank | MealTicket | Nunchaku
+            | OddlySmoothStone | Omamori | Orichalcum | PenNib | PotionBelt | PreservedInsect
+            | RegalPillow | SmilingMask | Strawberry | TinyChest | ToyOrnithopter | Vajra
+            | WarPaint | Whetstone | RedSkull | SnakeSkull | DataDisk | Damaru => Common,
+            BlueCandle | BottledFlame | BottledLightning | BottledTornado | DarkstonePeriapt
+            | EternalFeather | FrozenEgg | GremlinHorn | HornCleat | InkBottle | Kunai
+            | LetterOpener | Matryoshka | MeatOnTheBone | MercuryHourglass | MoltenEgg
+            | MummifiedHand | OrnamentalFan | Pantograph | Pear | QuestionCard | Shuriken
+            | SingingBowl | StrikeDummy | Sundial | ToxicEgg | WhiteBeastStatue | PaperPhrog
+            | SelfFormingClay | NinjaScroll | PaperKrane | GoldPlatedCables | SymbioticVirus
+            | TeardropLocket | Duality => Uncommon,
+            BirdFacedUrn | Calipers | CaptainsWheel | DeadBranch | DuVuDoll | FossilizedHelix
+            | GamblingChip | Ginger | Girya | IceCream | IncenseBurner | LizardTail | Mango
+            | OldCoin | PeacePipe | Pocketwatch | PrayerWheel | Shovel | StoneCalendar
+            | ThreadAndNeedle | Torii | TungstenRod | Turnip | UnceasingTop | WingBoots
+            | ChampionBelt | CharonsAshes | MagicFlower | TheSpecimen | Tingsha | ToughBandages
+            | EmotionChip | CloakClasp | GoldenEye => Rare,
+            Abacus | Cauldron | ChemicalX | ClockworkSouvenir | Courier | DollysMirror
+            | FrozenEye | HandDrill | MedicalKit | MembershipCard | OrangePellets | Orrery
+            | PrismaticShard | SlingOfCourage | StrangeSpoon | Toolbox | LeesWaffle | Brimstone
+            | TwistedFunnel | RunicCapacitor | Melange => Shop,
+            Astrolabe | BlackStar | BustedCrown | CallingBell | CoffeeDripper | CursedKey
+            | Ectoplasm | EmptyCage | FusionHammer | PandorasBox | PhilosophersStone
+            | RunicDome | RunicPyramid | SacredBark | SlaversCollar | SneckoEye | Sozu
+            | TinyHouse | VelvetChoker | BlackBlood | MarkOfPain | RunicCube | HoveringKite
+            | RingOfTheSerpent | WristBlade | FrozenCore | Inserter | NuclearBattery
+            | HolyWater | VioletLotus => Boss,
+            BloodyIdol | Circlet | CultistMask | Enchiridion | FaceOfCleric | GoldenIdol
+            | GremlinVisage | MarkOfTheBloom | MutagenicStrength | Necronomicon | NeowsLament
+            | NilrysCodex | NlothsGift | NlothsHungryFace | OddMushroom | RedCirclet | RedMask
+            | SpiritPoop | SsserpentHead | WarpedTongs => Special,
+        }
+    }
+
+    // `None` for relics every character can find
+    pub const fn character(self) -> Option<Character> {
+        let relic = self as usize;
+        if relic < Relic::BlackBlood as usize {
+            None
+        } else if relic < Relic::HoveringKite as usize {
+            Some(Character::Ironclad)
+        } else if relic < Relic::CrackedCore as usize {
+            Some(Character::Silent)
+        } else if relic < Relic::CloakClasp as usize {
+            Some(Character::Defect)
+        } else {
+            Some(Character::Watcher)
+        }
+    }
+
+    // `getPrice`, before the merchant's roll
+    pub const fn price(self) -> u32 {
+        match self.tier() {
+            Tier::Common | Tier::Shop => 150,
+            Tier::Uncommon => 250,
+            Tier::Starter | Tier::Rare => 300,
+            Tier::Special => 400,
+            Tier::Boss => 999,
+        }
+    }
+
+    // the floor and room checks of `canSpawn`; checks on the deck, like the bottles', are
+    // assumed to pass
+    pub const fn can_spawn(self, floor: usize, shop: bool) -> bool {
+        use Relic::*;
+        match self {
+            MawBank | OldCoin | SmilingMask => floor <= 48 && !shop,
+            AncientTeaSet | CeramicFish | Courier | DreamCatcher | Girya | JuzuBracelet
+            | MealTicket | PeacePipe | PotionBelt | PrayerWheel | QuestionCard | RegalPillow
+            | Shovel | SingingBowl => floor <= 48,
+            FrozenEgg | Matryoshka | MoltenEgg | ToxicEgg | WingBoots => floor <= 40,
+            TinyChest => floor <= 35,
+            // Act I only
+            Ectoplasm => floor <= 17,
+            _ => true,
+        }
+    }
+}
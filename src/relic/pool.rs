@@ -0,0 +1,336 @@
+use libgdx_xs128::{rng::Random, RandomXS128};
+use strum::EnumCount;
+
+use crate::{
+    character::Character,
+    rng::{java::JavaRandom, Stream},
+    seed::Seed,
+};
+
+use super::{Relic, Tier};
+
+// the shared common pool is the largest
+const MAX_POOL_LEN: usize = 33;
+
+// the tiers `initializeRelicList` fills and shuffles, in that order
+pub const POOL_TIERS: [Tier; 5] = [
+    Tier::Common,
+    Tier::Uncommon,
+    Tier::Rare,
+    Tier::Shop,
+    Tier::Boss,
+];
+
+#[derive(Clone, Copy)]
+pub struct RelicPool {
+    relics: [Relic; MAX_POOL_LEN],
+    len: usize,
+}
+
+impl RelicPool {
+    // the game hands out Circlets once a pool runs dry
+    const EMPTY: Self = Self {
+        relics: [Relic::Circlet; MAX_POOL_LEN],
+        len: 0,
+    };
+
+    const fn push(&mut self, relic: Relic) {
+        self.relics[self.len] = relic;
+        self.len += 1;
+    }
+
+    pub fn as_slice(&self) -> &[Relic] {
+        &self.relics[..self.len]
+    }
+
+    // `returnEndRandomRelicKey`, which skips relics that can no longer spawn
+    fn take_last(&mut self, floor: usize, shop: bool) -> Option<Relic> {
+        while self.len > 0 {
+            self.len -= 1;
+            let relic = self.relics[self.len];
+            if relic.can_spawn(floor, shop) {
+                return Some(relic);
+            }
+        }
+        None
+    }
+
+    fn shuffle(&mut self, rng: &mut Random) {
+        let len = self.len;
+        JavaRandom::new(rng.next_u64() as i64).shuffle(&mut self.relics[..len]);
+    }
+}
+
+// the Act I boss chest, unless Neow's boss swap already took the first relic
+pub const BOSS_OFFER_LEN: usize = 3;
+
+// the pools at the start of a run, assuming every relic is unlocked
+#[derive(Clone, Copy)]
+pub struct RelicPools {
+    pub common: RelicPool,
+    pub uncommon: RelicPool,
+    pub rare: RelicPool,
+    pub shop: RelicPool,
+    pub boss: RelicPool,
+}
+
+impl RelicPools {
+    // in `RelicLibrary`'s hash map order, before the shuffle
+    pub const fn unshuffled(character: Character) -> Self {
+        let [common, uncommon, rare, shop, boss] = UNSHUFFLED_POOLS[character as usize];
+        Self {
+            common,
+            uncommon,
+            rare,
+            shop,
+            boss,
+        }
+    }
+
+    pub fn new(character: Character, seed: &Seed) -> Self {
+        Self::generate(character, &mut Stream::Relic.rng(seed))
+    }
+
+    // each pool is shuffled by a `java.util.Random` seeded from the relic stream
+    pub fn generate(character: Character, rng: &mut Random) -> Self {
+        let mut pools = Self::unshuffled(character);
+        pools.common.shuffle(rng);
+        pools.uncommon.shuffle(rng);
+        pools.rare.shuffle(rng);
+        pools.shop.shuffle(rng);
+        pools.boss.shuffle(rng);
+        pools
+    }
+
+    // only the boss pool, skipping the four shuffles before it
+    pub fn generate_boss(character: Character, rng: &mut Random) -> RelicPool {
+        rng.advance(4);
+        let mut pool = Self::unshuffled(character).boss;
+        pool.shuffle(rng);
+        pool
+    }
+
+    // relics are taken from the front; empty for tiers without a pool
+    pub fn pool(&self, tier: Tier) -> &[Relic] {
+        match tier {
+            Tier::Common => self.common.as_slice(),
+            Tier::Uncommon => self.uncommon.as_slice(),
+            Tier::Rare => self.rare.as_slice(),
+            Tier::Shop => self.shop.as_slice(),
+            Tier::Boss => self.boss.as_slice(),
+            Tier::Starter | Tier::Special => &[],
+        }
+    }
+
+    // what the merchant stocks: relics are taken from the back, and an empty pool falls
+    // through to the next rarer one
+    pub fn take_last(&mut self, tier: Tier, floor: usize, shop: bool) -> Relic {
+        let (pool, fallback) = match tier {
+            Tier::Common => (&mut self.common, Some(Tier::Uncommon)),
+            Tier::Uncommon => (&mut self.uncommon, Some(Tier::Rare)),
+            Tier::Shop => (&mut self.shop, Some(Tier::Uncommon)),
+            Tier::Rare => (&mut self.rare, None),
+            Tier::Boss => {
+                return self
+                    .boss
+                    .take_last(floor, shop)
+                    .unwrap_or(Relic::RedCirclet)
+            }
+            Tier::Starter | Tier::Special => return Relic::Circlet,
+        };
+        match (pool.take_last(floor, shop), fallback) {
+            (Some(relic), _) => relic,
+            (None, Some(fallback)) => self.take_last(fallback, floor, shop),
+            (None, None) => Relic::Circlet,
+        }
+    }
+
+    // every relic passes `canSpawn` with the starting deck on floor 0
+    pub fn first(&self, tier: Tier) -> Option<Relic> {
+        self.pool(tier).first().copied()
+    }
+}
+
+static UNSHUFFLED_POOLS: [[RelicPool; POOL_TIERS.len()]; Character::COUNT] = unshuffled_pools();
+
+const fn java_hash_code(id: &str) -> i32 {
+    let bytes = id.as_bytes();
+    let mut hash = 0i32;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash = hash.wrapping_mul(31).wrapping_add(bytes[i] as i32);
+        i += 1;
+    }
+    hash
+}
+
+// shared relics, then each character's own
+const fn map_index(character: Option<Character>) -> usize {
+    match character {
+        None => 0,
+        Some(character) => 1 + character as usize,
+    }
+}
+
+const MAP_COUNT: usize = Character::COUNT + 1;
+
+// a default `HashMap` doubles once it is more than three quarters full
+const fn map_capacities() -> [usize; MAP_COUNT] {
+    let mut lens = [0; MAP_COUNT];
+    let mut i = 0;
+    while i < Relic::COUNT {
+        let Some(relic) = Relic::from_repr(i) else {
+            unreachable!()
+        };
+        lens[map_index(relic.character())] += 1;
+        i += 1;
+    }
+    let mut capacities = [16; MAP_COUNT];
+    let mut map = 0;
+    while map < MAP_COUNT {
+        while lens[map] > capacities[map] * 3 / 4 {
+            capacities[map] *= 2;
+        }
+        map += 1;
+    }
+    capacities
+}
+
+// each map's relics as `HashMap` iterates them: bucket by bucket, colliding keys in
+// insertion order
+const fn iteration_order() -> [Relic; Relic::COUNT] {
+    let capacities = map_capacities();
+    let mut keys = [(0, Relic::Circlet); Relic::COUNT];
+    let mut i = 0;
+    while i < Relic::COUNT {
+        let Some(relic) = Relic::from_repr(i) else {
+            unreachable!()
+        };
+        let map = map_index(relic.character());
+        let hash = java_hash_code(relic.id()) as u32;
+        let bucket = (hash ^ (hash >> 16)) as usize & (capacities[map] - 1);
+        keys[i] = (map << 16 | bucket, relic);
+        i += 1;
+    }
+    // a stable insertion sort keeps the insertion order within a bucket
+    let mut i = 1;
+    while i < Relic::COUNT {
+        let mut j = i;
+        while j > 0 && keys[j - 1].0 > keys[j].0 {
+            let key = keys[j];
+            keys[j] = keys[j - 1];
+            keys[j - 1] = key;
+            j -= 1;
+        }
+        i += 1;
+    }
+    let mut order = [Relic::Circlet; Relic::COUNT];
+    let mut i = 0;
+    while i < Relic::COUNT {
+        order[i] = keys[i].1;
+        i += 1;
+    }
+    order
+}
+
+const ITERATION_ORDER: [Relic; Relic::COUNT] = iteration_order();
+
+// `populateRelicPool` walks the shared relics, then the character's own
+const fn push_map(pool: &mut RelicPool, map: usize, tier: Tier) {
+    let mut i = 0;
+    while i < Relic::COUNT {
+        let relic = ITERATION_ORDER[i];
+        if map_index(relic.character()) == map && relic.tier() as usize == tier as usize {
+            pool.push(relic);
+        }
+        i += 1;
+    }
+}
+
+const fn unshuffled_pools() -> [[RelicPool; POOL_TIERS.len()]; Character::COUNT] {
+    let mut pools = [[RelicPool::EMPTY; POOL_TIERS.len()]; Character::COUNT];
+    let mut i = 0;
+    while i < Character::COUNT {
+        let Some(character) = Character::from_repr(i) else {
+            unreachable!()
+        };
+        let mut j = 0;
+        while j < POOL_TIERS.len() {
+            push_map(&mut pools[i][j], map_index(None), POOL_TIERS[j]);
+            push_map(&mut pools[i][j], map_index(Some(character)), POOL_TIERS[j]);
+            j += 1;
+        }
+        i += 1;
+    }
+    pools
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod relic_pool_tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_unshuffled_pools() {
+        for character in Character::iter() {
+            let pools = RelicPools::unshuffled(character);
+            for tier in POOL_TIERS {
+                let expected = Relic::iter()
+                    .filter(|relic| relic.tier() == tier)
+                    .filter(|relic| relic.character().is_none_or(|other| other == character))
+                    .count();
+                let pool = pools.pool(tier);
+                assert_eq!(pool.len(), expected, "{character:?} {tier:?}");
+                assert!(pool.iter().all(|relic| relic.tier() == tier));
+            }
+        }
+    }
+
+    // checked against `populateRelicPool` and `Collections.shuffle` run on the JVM
+    #[test]
+    fn test_first_relics() {
+        use Relic::*;
+        let runs = [
+            (b"    1J432TK4I", Character::Ironclad, MawBank, RunicDome),
+            (b"           CY", Character::Ironclad, TinyChest, TinyHouse),
+            (
+                b" 3VQ6AFFBYP1Z",
+                Character::Silent,
+                PreservedInsect,
+                RunicPyramid,
+            ),
+            (
+                b"           CY",
+                Character::Watcher,
+                TinyChest,
+                CoffeeDripper,
+            ),
+        ];
+        for (seed, character, common, boss) in runs {
+            let pools = RelicPools::new(character, &Seed::from(seed));
+            assert_eq!(pools.first(Tier::Common), Some(common));
+            assert_eq!(pools.first(Tier::Boss), Some(boss));
+        }
+    }
+
+    #[test]
+    fn test_shuffled_pools() {
+        use Relic::*;
+        let seed = Seed::from(374388466716482469i64);
+        let expected = [
+            [PreservedInsect, TinyChest, Omamori],
+            [ToxicEgg, SelfFormingClay, OrnamentalFan],
+            [UnceasingTop, IncenseBurner, ChampionBelt],
+            [ClockworkSouvenir, FrozenEye, PrismaticShard],
+            [RunicPyramid, Ectoplasm, BlackBlood],
+        ];
+        let pools = RelicPools::new(Character::Ironclad, &seed);
+        for (tier, expected) in POOL_TIERS.into_iter().zip(expected) {
+            assert_eq!(pools.pool(tier)[..3], expected, "{tier:?}");
+        }
+        let boss = RelicPools::generate_boss(Character::Ironclad, &mut Stream::Relic.rng(&seed));
+        assert_eq!(boss.as_slice(), pools.boss.as_slice());
+    }
+}
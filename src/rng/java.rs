@@ -0,0 +1,75 @@
+// `java.util.Random`, which the game seeds from its own streams for `Collections.shuffle`
+pub struct JavaRandom {
+    seed: u64,
+}
+
+impl JavaRandom {
+    const MULTIPLIER: u64 = 0x5DEECE66D;
+    const MASK: u64 = (1 << 48) - 1;
+
+    pub const fn new(seed: i64) -> Self {
+        Self {
+            seed: (seed as u64 ^ Self::MULTIPLIER) & Self::MASK,
+        }
+    }
+
+    const fn next(&mut self, bits: u32) -> i32 {
+        self.seed = self.seed.wrapping_mul(Self::MULTIPLIER).wrapping_add(0xB) & Self::MASK;
+        (self.seed >> (48 - bits)) as i32
+    }
+
+    pub const fn next_int(&mut self) -> i32 {
+        self.next(32)
+    }
+
+    pub const fn next_int_bounded(&mut self, bound: i32) -> i32 {
+        let mut r = self.next(31);
+        let m = bound - 1;
+        if bound & m == 0 {
+            return ((bound as i64 * r as i64) >> 31) as i32;
+        }
+        let mut u = r;
+        loop {
+            r = u % bound;
+            // rerolls the last, incomplete multiple of `bound`
+            if u.wrapping_sub(r).wrapping_add(m) >= 0 {
+                return r;
+            }
+            u = self.next(31);
+        }
+    }
+
+    // `Collections.shuffle` on an `ArrayList`
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (2..=slice.len()).rev() {
+            let j = self.next_int_bounded(i as i32) as usize;
+            slice.swap(i - 1, j);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod java_random_tests {
+    use super::*;
+
+    #[test]
+    fn test_java_values() {
+        let mut rng = JavaRandom::new(42);
+        assert_eq!(rng.next_int(), -1170105035);
+        let mut rng = JavaRandom::new(42);
+        assert_eq!(rng.next_int_bounded(10), 0);
+        assert_eq!(rng.next_int_bounded(10), 3);
+        let mut rng = JavaRandom::new(-7);
+        assert_eq!(rng.next_int_bounded(1_000_000_007), 577934662);
+        assert_eq!(rng.next_int_bounded(3), 0);
+        assert_eq!(rng.next_int_bounded(1 << 30), 722822795);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut list = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        JavaRandom::new(42).shuffle(&mut list);
+        assert_eq!(list, [4, 6, 2, 1, 7, 9, 8, 5, 3, 0]);
+    }
+}
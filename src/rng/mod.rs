@@ -0,0 +1,292 @@
+pub mod java;
+
+use libgdx_xs128::{rng::Random, RandomXS128};
+use strum_macros::{EnumCount, EnumIter};
+
+use crate::{map::Act, seed::Seed};
+
+// the game's named per-run streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, EnumCount)]
+pub enum Stream {
+    Monster,
+    Event,
+    Merchant,
+    Card,
+    Treasure,
+    Relic,
+    Potion,
+    MonsterHp,
+    Ai,
+    Shuffle,
+    CardRandom,
+    Misc,
+    Neow,
+}
+
+impl Stream {
+    // reseeded with `seed + floorNum` on every floor transition
+    pub const fn is_floor_scoped(&self) -> bool {
+        matches!(
+            self,
+            Stream::MonsterHp | Stream::Ai | Stream::Shuffle | Stream::CardRandom | Stream::Misc
+        )
+    }
+
+    // every stream starts the run seeded with the seed itself
+    pub fn rng(&self, seed: &Seed) -> Random {
+        seed.rng()
+    }
+
+    // the state on entering `floor`, only known up front for floor-scoped streams
+    pub fn floor_rng(&self, seed: &Seed, floor: usize) -> Option<Random> {
+        self.is_floor_scoped()
+            .then(|| seed.offset_rng(floor as i64))
+    }
+}
+
+// the floor-scoped streams as they are on entering a floor
+pub struct FloorRng {
+    pub monster_hp: Random,
+    pub ai: Random,
+    pub shuffle: Random,
+    pub card_random: Random,
+    pub misc: Random,
+}
+
+impl FloorRng {
+    pub fn new(seed: &Seed, floor: usize) -> Self {
+        let offset = floor as i64;
+        Self {
+            monster_hp: seed.offset_rng(offset),
+            ai: seed.offset_rng(offset),
+            shuffle: seed.offset_rng(offset),
+            card_random: seed.offset_rng(offset),
+            misc: seed.offset_rng(offset),
+        }
+    }
+
+    pub fn stream(&mut self, stream: Stream) -> Option<&mut Random> {
+        match stream {
+            Stream::MonsterHp => Some(&mut self.monster_hp),
+            Stream::Ai => Some(&mut self.ai),
+            Stream::Shuffle => Some(&mut self.shuffle),
+            Stream::CardRandom => Some(&mut self.card_random),
+            Stream::Misc => Some(&mut self.misc),
+            _ => None,
+        }
+    }
+}
+
+// libgdx `nextFloat`, the top 24 bits scaled in double precision
+pub fn next_f32(rng: &mut Random) -> f32 {
+    ((rng.next_u64() >> 40) as f64 * 5.960_464_477_539_063E-8) as f32
+}
+
+// the game's `randomBoolean(chance)`
+pub fn random_boolean(rng: &mut Random, chance: f32) -> bool {
+    next_f32(rng) < chance
+}
+
+// `cardRng.setCounter` in `dungeonTransitionSetup`: a counter strictly inside one of the
+// first three blocks of 250 calls jumps to the end of that block
+pub const fn act_card_counter(counter: u32) -> u32 {
+    match counter {
+        1..=249 => 250,
+        251..=499 => 500,
+        501..=749 => 750,
+        _ => counter,
+    }
+}
+
+pub struct RunRng {
+    seed: Seed,
+    floor: usize,
+    act: Act,
+    pub monster: Random,
+    pub event: Random,
+    pub merchant: Random,
+    pub card: Random,
+    pub treasure: Random,
+    pub relic: Random,
+    pub potion: Random,
+    pub monster_hp: Random,
+    pub ai: Random,
+    pub shuffle: Random,
+    pub card_random: Random,
+    pub misc: Random,
+    pub neow: Random,
+}
+
+impl RunRng {
+    pub fn new(seed: &Seed) -> Self {
+        Self {
+            seed: seed.clone(),
+            floor: 0,
+            act: Act::One,
+            monster: Stream::Monster.rng(seed),
+            event: Stream::Event.rng(seed),
+            merchant: Stream::Merchant.rng(seed),
+            card: Stream::Card.rng(seed),
+            treasure: Stream::Treasure.rng(seed),
+            relic: Stream::Relic.rng(seed),
+            potion: Stream::Potion.rng(seed),
+            monster_hp: Stream::MonsterHp.rng(seed),
+            ai: Stream::Ai.rng(seed),
+            shuffle: Stream::Shuffle.rng(seed),
+            card_random: Stream::CardRandom.rng(seed),
+            misc: Stream::Misc.rng(seed),
+            neow: Stream::Neow.rng(seed),
+        }
+    }
+
+    pub fn seed(&self) -> &Seed {
+        &self.seed
+    }
+
+    pub fn floor(&self) -> usize {
+        self.floor
+    }
+
+    pub fn act(&self) -> Act {
+        self.act
+    }
+
+    pub fn stream(&mut self, stream: Stream) -> &mut Random {
+        match stream {
+            Stream::Monster => &mut self.monster,
+            Stream::Event => &mut self.event,
+            Stream::Merchant => &mut self.merchant,
+            Stream::Card => &mut self.card,
+            Stream::Treasure => &mut self.treasure,
+            Stream::Relic => &mut self.relic,
+            Stream::Potion => &mut self.potion,
+            Stream::MonsterHp => &mut self.monster_hp,
+            Stream::Ai => &mut self.ai,
+            Stream::Shuffle => &mut self.shuffle,
+            Stream::CardRandom => &mut self.card_random,
+            Stream::Misc => &mut self.misc,
+            Stream::Neow => &mut self.neow,
+        }
+    }
+
+    // the map of each act has its own stream, see `Seed::map_rng`
+    pub fn map(&self, act: Act) -> Random {
+        self.seed.map_rng(act)
+    }
+
+    // entering the next room reseeds the floor-scoped streams; the others carry on
+    pub fn next_floor(&mut self) {
+        self.floor += 1;
+        let FloorRng {
+            monster_hp,
+            ai,
+            shuffle,
+            card_random,
+            misc,
+        } = FloorRng::new(&self.seed, self.floor);
+        self.monster_hp = monster_hp;
+        self.ai = ai;
+        self.shuffle = shuffle;
+        self.card_random = card_random;
+        self.misc = misc;
+    }
+
+    // entering the next act advances `card` as `setCounter` does, with one draw per call;
+    // `card_counter` is the game's count of calls on `card` so far, and the new count is returned
+    pub fn next_act(&mut self, card_counter: u32) -> u32 {
+        let counter = act_card_counter(card_counter);
+        self.card.advance(counter - card_counter);
+        self.act = match self.act {
+            Act::One => Act::Two,
+            Act::Two => Act::Three,
+            Act::Three | Act::Four => Act::Four,
+        };
+        counter
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod run_rng_tests {
+    use libgdx_xs128::RandomXS128;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_floor_transitions() {
+        let seed = Seed::from(b"    1J432TK4I");
+        let mut rng = RunRng::new(&seed);
+        for stream in Stream::iter() {
+            assert_eq!(
+                rng.stream(stream).next_u64(),
+                seed.rng().next_u64(),
+                "{stream:?}"
+            );
+        }
+        for floor in 1..=3 {
+            rng.next_floor();
+            assert_eq!(rng.floor(), floor);
+            for stream in Stream::iter() {
+                let next = rng.stream(stream).next_u64();
+                let mut expected = if stream.is_floor_scoped() {
+                    seed.offset_rng(floor as i64)
+                } else {
+                    // one draw per floor so far
+                    let mut carried = seed.rng();
+                    carried.advance(floor as u32);
+                    carried
+                };
+                assert_eq!(next, expected.next_u64(), "{stream:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_act_transitions() {
+        let seed = Seed::from(b"    1J432TK4I");
+        let mut rng = RunRng::new(&seed);
+        let mut counter = 0;
+        // calls on `card` during each act, then the counter after the transition
+        for (act, calls, jumped) in [
+            (Act::Two, 36, 250),
+            (Act::Three, 0, 250),
+            (Act::Four, 51, 500),
+        ] {
+            rng.card.advance(calls);
+            counter += calls;
+            counter = rng.next_act(counter);
+            assert_eq!(counter, jumped);
+            assert_eq!(rng.act(), act);
+        }
+        let mut card = seed.rng();
+        card.advance(500);
+        assert_eq!(rng.card.next_u64(), card.next_u64());
+        // the other streams carry on untouched
+        assert_eq!(rng.relic.next_u64(), seed.rng().next_u64());
+        assert_eq!(act_card_counter(0), 0);
+        assert_eq!(act_card_counter(749), 750);
+        assert_eq!(act_card_counter(760), 760);
+    }
+
+    #[test]
+    fn test_floor_lookup() {
+        let seed = Seed::from(b"    1J432TK4I");
+        let mut rng = RunRng::new(&seed);
+        for _ in 0..15 {
+            rng.next_floor();
+        }
+        let mut floor = FloorRng::new(&seed, 15);
+        for stream in Stream::iter() {
+            match (floor.stream(stream), stream.floor_rng(&seed, 15)) {
+                (Some(looked_up), Some(mut expected)) => {
+                    let next = looked_up.next_u64();
+                    assert_eq!(next, rng.stream(stream).next_u64(), "{stream:?}");
+                    assert_eq!(next, expected.next_u64(), "{stream:?}");
+                }
+                (None, None) => assert!(!stream.is_floor_scoped()),
+                _ => panic!("{stream:?} disagrees on being floor-scoped"),
+            }
+        }
+    }
+}
@@ -58,14 +58,15 @@ impl From<SeedString> for Seed {
 
 impl From<Seed> for SeedString {
     fn from(value: Seed) -> Self {
-        let mut seed = value.seed;
+        // negative seeds are written as their unsigned value, as the game does
+        let mut seed = value.seed as u64;
         let mut s: [u8; 13] = [b' '; 13];
         for i in (0..13).rev() {
             if seed == 0 {
                 break;
             }
-            let c = seed % BASE;
-            seed /= BASE;
+            let c = seed % BASE as u64;
+            seed /= BASE as u64;
             let c = ALPHABET[c as usize];
             s[i] = c;
         }
@@ -168,5 +169,8 @@ mod test_seed_conversions {
             .unwrap()
             .into();
         assert_eq!(seed.seed, -706882697283956955);
+
+        let seed_string = SeedString::from(seed);
+        assert_eq!(seed_string, "58QVGLNE8PU3W".parse::<SeedString>().unwrap());
     }
 }
@@ -16,6 +16,10 @@ pub struct Seed {
 }
 
 impl Seed {
+    pub const fn seed(&self) -> i64 {
+        self.seed
+    }
+
     pub fn rng(&self) -> Random {
         self.offset_rng(0)
     }
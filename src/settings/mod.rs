@@ -0,0 +1,401 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    card::{reward::filters::CardRewardFilter, Card},
+    character::Character,
+    filter::SeedFilter,
+    map::{
+        assign_nodes::buffed_elite::EliteBuff,
+        filters::bottleneck::{Bottleneck, BurningEliteBottleneck},
+        in_neighborhood::in_vec::InVec,
+        out_neighborhood::out_vec::OutVec,
+    },
+    neow::{
+        filters::{NeowFilter, NeowPattern},
+        Blessing, Drawback, FirstBonus, SecondBonus, ThirdBonus,
+    },
+    seed::{Seed, SeedString},
+    sieve::Sieve,
+    unlock::Unlocks,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    ParseSeedString(crate::seed::from::Error),
+    EmptyRange(u64, u64),
+    InvalidFloor(usize),
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchSettings {
+    pub start: SeedBound,
+    pub end: SeedBound,
+    pub character: Character,
+    #[serde(default)]
+    pub ascension: bool,
+    #[serde(default)]
+    pub unlocks: Option<Unlocks>,
+    #[serde(default)]
+    pub blessing: Blessing,
+    pub filter: FilterSettings,
+}
+
+// either a numeric seed or a seed string such as "1J432TK4I"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SeedBound {
+    Seed(u64),
+    SeedString(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterSettings {
+    Bottleneck {
+        floor: usize,
+    },
+    BurningElite {
+        floor: usize,
+        #[serde(default)]
+        buffs: Option<Vec<EliteBuff>>,
+    },
+    RejectedCards(Vec<Card>),
+    Neow(NeowSettings),
+    All(Vec<FilterSettings>),
+    Any(Vec<FilterSettings>),
+    Not(Box<FilterSettings>),
+}
+
+// each list, when present, holds the accepted options
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NeowSettings {
+    pub first: Option<Vec<FirstBonus>>,
+    pub second: Option<Vec<SecondBonus>>,
+    pub drawback: Option<Vec<Drawback>>,
+    pub third: Option<Vec<ThirdBonus>>,
+}
+
+impl SeedBound {
+    pub fn seed(&self) -> Result<u64, Error> {
+        match self {
+            SeedBound::Seed(seed) => Ok(*seed),
+            SeedBound::SeedString(seed) => {
+                let seed: SeedString = seed.parse().map_err(Error::ParseSeedString)?;
+                Ok(Seed::from(seed).seed as u64)
+            }
+        }
+    }
+}
+
+impl SearchSettings {
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn sieve(&self) -> Result<Sieve<SettingsFilter<'_>>, Error> {
+        let (start, end) = (self.start.seed()?, self.end.seed()?);
+        if start > end {
+            return Err(Error::EmptyRange(start, end));
+        }
+        let filter = self.filter.build(self)?;
+        Ok(Sieve::new(start, end, filter))
+    }
+}
+
+impl FilterSettings {
+    fn build<'a>(&'a self, settings: &SearchSettings) -> Result<SettingsFilter<'a>, Error> {
+        let SearchSettings {
+            character,
+            ascension,
+            unlocks,
+            blessing,
+            ..
+        } = *settings;
+        let build_all = |filters: &'a [FilterSettings]| {
+            filters
+                .iter()
+                .map(|filter| filter.build(settings))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match self {
+            FilterSettings::Bottleneck { floor } => {
+                SettingsFilter::Bottleneck(Bottleneck::new(valid_floor(*floor)?))
+            }
+            FilterSettings::BurningElite { floor, buffs } => SettingsFilter::BurningElite(
+                BurningEliteBottleneck::new(valid_floor(*floor)?, buffs.as_deref())
+                    .with_ascension(ascension),
+            ),
+            FilterSettings::RejectedCards(cards) => {
+                SettingsFilter::RejectedCards(CardRewardFilter::new(character, unlocks, cards))
+            }
+            FilterSettings::Neow(neow) => SettingsFilter::Neow(neow.patterns(), blessing),
+            FilterSettings::All(filters) => SettingsFilter::All(build_all(filters)?),
+            FilterSettings::Any(filters) => SettingsFilter::Any(build_all(filters)?),
+            FilterSettings::Not(filter) => SettingsFilter::Not(Box::new(filter.build(settings)?)),
+        })
+    }
+}
+
+const fn valid_floor(floor: usize) -> Result<usize, Error> {
+    match floor {
+        1..=crate::map::HEIGHT => Ok(floor),
+        _ => Err(Error::InvalidFloor(floor)),
+    }
+}
+
+impl NeowSettings {
+    // one pattern per listed slot, all of which must match
+    fn patterns(&self) -> Vec<NeowPattern<'_>> {
+        let mut patterns = Vec::new();
+        if let Some(first) = &self.first {
+            patterns.push(NeowPattern::First(first));
+        }
+        if let Some(second) = &self.second {
+            patterns.push(NeowPattern::Second(second));
+        }
+        if let Some(drawback) = &self.drawback {
+            patterns.push(NeowPattern::Drawback(drawback));
+        }
+        if let Some(third) = &self.third {
+            patterns.push(NeowPattern::Third(third));
+        }
+        patterns
+    }
+}
+
+// the filter tree built from `FilterSettings`, borrowing its card and buff lists;
+// each leaf reads its own stream of the seed
+pub enum SettingsFilter<'a> {
+    Bottleneck(Bottleneck<InVec, OutVec>),
+    BurningElite(BurningEliteBottleneck<'a>),
+    RejectedCards(CardRewardFilter<'a, 3>),
+    Neow(Vec<NeowPattern<'a>>, Blessing),
+    All(Vec<SettingsFilter<'a>>),
+    Any(Vec<SettingsFilter<'a>>),
+    Not(Box<SettingsFilter<'a>>),
+}
+
+impl<'a> SeedFilter for SettingsFilter<'a> {
+    fn reject<T: Into<Seed>>(&self, seed: T) -> bool {
+        let seed: Seed = seed.into();
+        match self {
+            SettingsFilter::Bottleneck(filter) => filter.reject(seed),
+            SettingsFilter::BurningElite(filter) => filter.reject(seed),
+            SettingsFilter::RejectedCards(filter) => filter.reject(seed),
+            SettingsFilter::Neow(patterns, blessing) => NeowFilter::new(NeowPattern::All(patterns))
+                .with_blessing(*blessing)
+                .reject(seed),
+            SettingsFilter::All(filters) => {
+                filters.iter().any(|filter| filter.reject(seed.clone()))
+            }
+            SettingsFilter::Any(filters) => {
+                filters.iter().all(|filter| filter.reject(seed.clone()))
+            }
+            SettingsFilter::Not(filter) => !filter.reject(seed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod settings_tests {
+    use crate::{
+        filter::combinator::{And, Not},
+        map::_ONE_PATH_BURNING_ELITE_BOTTLENECKS,
+        unlock::UnlockLevel,
+    };
+
+    use super::*;
+
+    const SETTINGS: &str = r#"{
+        "start": "1J432TK0A",
+        "end": "1J432TKZZ",
+        "character": "Silent",
+        "ascension": true,
+        "filter": { "all": [
+            { "burning_elite": { "floor": 6, "buffs": ["Strength", "Regenerate"] } },
+            { "not": { "rejected_cards": ["Prepared"] } },
+            { "neow": { "drawback": ["NoGold", "Curse"] } }
+        ] }
+    }"#;
+
+    #[test]
+    fn test_parse_settings() {
+        let settings = SearchSettings::from_json(SETTINGS).unwrap();
+        assert_eq!(settings.start, SeedBound::SeedString("1J432TK0A".into()));
+        assert_eq!(settings.character, Character::Silent);
+        assert_eq!(settings.unlocks, None);
+        let neow = NeowSettings {
+            drawback: Some(alloc::vec![Drawback::NoGold, Drawback::Curse]),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.filter,
+            FilterSettings::All(alloc::vec![
+                FilterSettings::BurningElite {
+                    floor: 6,
+                    buffs: Some(alloc::vec![EliteBuff::Strength, EliteBuff::Regenerate]),
+                },
+                FilterSettings::Not(Box::new(FilterSettings::RejectedCards(alloc::vec![
+                    Card::Prepared
+                ]))),
+                FilterSettings::Neow(neow),
+            ])
+        );
+        let json = settings.to_json().unwrap();
+        assert_eq!(SearchSettings::from_json(&json).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_settings_sieve_matches_filters() {
+        let settings = SearchSettings::from_json(SETTINGS).unwrap();
+        let sieve = settings.sieve().unwrap();
+        let (start, end) = (sieve.start(), sieve.end());
+        assert_eq!(Seed::from(start), Seed::from(b"    1J432TK0A"));
+        assert_eq!(Seed::from(end), Seed::from(b"    1J432TKZZ"));
+
+        const BUFFS: [EliteBuff; 2] = [EliteBuff::Strength, EliteBuff::Regenerate];
+        const CARDS: And<BurningEliteBottleneck, Not<CardRewardFilter<3>>> = And(
+            BurningEliteBottleneck::new(6, Some(&BUFFS)),
+            Not(CardRewardFilter::new(
+                Character::Silent,
+                None,
+                &[Card::Prepared],
+            )),
+        );
+        const NEOW: NeowFilter<'_> =
+            NeowFilter::new(NeowPattern::Drawback(&[Drawback::NoGold, Drawback::Curse]));
+        for seed in (start..=end).chain([1, 2, 3]) {
+            let expected = CARDS.reject(seed) || NEOW.reject(seed);
+            assert_eq!(sieve.filter().reject(seed), expected, "{seed}");
+        }
+    }
+
+    // map filters read the map stream, card filters the raw seed and Neow its own stream
+    #[test]
+    fn test_mixed_streams() {
+        let settings = SearchSettings::from_json(
+            r#"{
+                "start": 1,
+                "end": 300,
+                "character": "Silent",
+                "filter": { "any": [
+                    { "bottleneck": { "floor": 6 } },
+                    { "all": [
+                        { "not": { "rejected_cards": ["Prepared"] } },
+                        { "not": { "neow": { "drawback": ["NoGold"] } } }
+                    ] }
+                ] }
+            }"#,
+        )
+        .unwrap();
+        let sieve = settings.sieve().unwrap();
+
+        const BOTTLENECK: Bottleneck<InVec, OutVec> = Bottleneck::const_default();
+        const CARDS: CardRewardFilter<3> =
+            CardRewardFilter::new(Character::Silent, None, &[Card::Prepared]);
+        const NEOW: NeowFilter<'_> = NeowFilter::new(NeowPattern::Drawback(&[Drawback::NoGold]));
+        let mut matched = 0;
+        for seed in 1u64..=300 {
+            let all = !CARDS.reject(seed) || !NEOW.reject(seed);
+            let expected = BOTTLENECK.reject(seed) && all;
+            assert_eq!(sieve.filter().reject(seed), expected, "{seed}");
+            matched += usize::from(!expected);
+        }
+        assert!(matched > 0);
+    }
+
+    #[test]
+    fn test_bottleneck_settings() {
+        let settings = SearchSettings {
+            start: SeedBound::Seed(1),
+            end: SeedBound::Seed(100),
+            character: Character::Ironclad,
+            ascension: false,
+            unlocks: Some(Unlocks::full()),
+            blessing: Blessing::Full,
+            filter: FilterSettings::Bottleneck { floor: 6 },
+        };
+        let sieve = settings.sieve().unwrap();
+        for &seed in _ONE_PATH_BURNING_ELITE_BOTTLENECKS {
+            assert!(!sieve.filter().reject::<Seed>(seed.into()));
+        }
+        assert!((1u64..=5).all(|seed| sieve.filter().reject(seed)));
+    }
+
+    #[test]
+    fn test_unlocked_card_settings() {
+        const UNLOCKS: Unlocks = Unlocks::new([UnlockLevel::Zero; 4]);
+        const FILTER: CardRewardFilter<3> =
+            CardRewardFilter::new(Character::Silent, Some(UNLOCKS), &[Card::Prepared]);
+        let settings = SearchSettings {
+            start: SeedBound::Seed(1),
+            end: SeedBound::Seed(500),
+            character: Character::Silent,
+            ascension: false,
+            unlocks: Some(UNLOCKS),
+            blessing: Blessing::Full,
+            filter: FilterSettings::RejectedCards(alloc::vec![Card::Prepared]),
+        };
+        let sieve = settings.sieve().unwrap();
+        for seed in 1u64..=500 {
+            assert_eq!(sieve.filter().reject(seed), FILTER.reject(seed));
+        }
+    }
+
+    #[test]
+    fn test_mini_blessing_settings() {
+        let mut settings = SearchSettings::from_json(
+            r#"{
+                "start": 1,
+                "end": 100,
+                "character": "Ironclad",
+                "blessing": "Mini",
+                "filter": { "neow": { "second": ["ThreeEnemyKill"] } }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(settings.blessing, Blessing::Mini);
+        let sieve = settings.sieve().unwrap();
+        assert!((1u64..=100).all(|seed| !sieve.filter().reject(seed)));
+
+        settings.filter = FilterSettings::Neow(NeowSettings {
+            second: Some(alloc::vec![SecondBonus::HundredGold]),
+            ..Default::default()
+        });
+        let sieve = settings.sieve().unwrap();
+        assert!((1u64..=100).all(|seed| sieve.filter().reject(seed)));
+
+        // the full blessing offers a hundred gold on some seeds
+        settings.blessing = Blessing::Full;
+        let sieve = settings.sieve().unwrap();
+        assert!((1u64..=100).any(|seed| !sieve.filter().reject(seed)));
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        assert!(matches!(
+            SearchSettings::from_json(r#"{ "start": 1, "end": 2, "character": "Silent" }"#),
+            Err(Error::Json(_))
+        ));
+        let mut settings = SearchSettings::from_json(SETTINGS).unwrap();
+        settings.filter = FilterSettings::Bottleneck { floor: 0 };
+        assert!(matches!(settings.sieve(), Err(Error::InvalidFloor(0))));
+        settings.end = SeedBound::SeedString("1J432TK0!".into());
+        assert!(matches!(settings.sieve(), Err(Error::ParseSeedString(_))));
+        settings.end = SeedBound::Seed(0);
+        assert!(matches!(settings.sieve(), Err(Error::EmptyRange(_, 0))));
+    }
+}
@@ -0,0 +1,328 @@
+use libgdx_xs128::{rng::Random, RandomXS128};
+
+use crate::{
+    card::{
+        pool::ColorlessCards,
+        reward::{CardRewarder, Offset},
+        Card, Kind, Rarity,
+    },
+    character::Character,
+    potion::{Potion, PotionPool},
+    relic::{pool::RelicPools, Relic, Tier},
+    rng::{next_f32, RunRng},
+    unlock::Unlocks,
+};
+
+// `ShopScreen.purgeCost`, which goes up after every removal
+const REMOVAL_PRICE: u32 = 75;
+const REMOVAL_INCREASE: u32 = 25;
+
+// two attacks, two skills and a power
+const COLORED_KINDS: [Kind; 5] = [
+    Kind::Attack,
+    Kind::Attack,
+    Kind::Skill,
+    Kind::Skill,
+    Kind::Power,
+];
+
+// a pool of one rarity is at most this many cards of one kind
+const MAX_KIND_LEN: usize = 40;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ShopItem<T> {
+    pub item: T,
+    pub price: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Shop {
+    pub colored: [ShopItem<Card>; 5],
+    pub colorless: [ShopItem<Card>; 2],
+    // the colored card on sale, whose price is already halved
+    pub sale: usize,
+    pub relics: [ShopItem<Relic>; 3],
+    pub potions: [ShopItem<Potion>; 3],
+    pub removal: u32,
+}
+
+impl Shop {
+    pub fn cards(&self) -> impl Iterator<Item = &ShopItem<Card>> {
+        self.colored.iter().chain(self.colorless.iter())
+    }
+
+    pub fn sells(&self, card: Card) -> bool {
+        self.cards().any(|item| item.item == card)
+    }
+
+    pub fn sells_relic(&self, relic: Relic) -> bool {
+        self.relics.iter().any(|item| item.item == relic)
+    }
+}
+
+pub struct Merchant {
+    cards: CardRewarder<'static, 1>,
+    colorless: ColorlessCards<'static>,
+    potions: PotionPool,
+    ascension: u8,
+}
+
+impl Merchant {
+    pub const fn new(character: Character, unlocks: Option<Unlocks>) -> Self {
+        Self {
+            cards: CardRewarder::new(character, unlocks),
+            colorless: ColorlessCards::new(),
+            potions: PotionPool::new(character),
+            ascension: 0,
+        }
+    }
+
+    // from ascension 16 everything costs a tenth more, rounded once the sale is applied
+    pub const fn with_ascension(self, ascension: u8) -> Self {
+        Self { ascension, ..self }
+    }
+
+    // the shop on the current floor of `rng`; `offset` is the card rarity offset left by
+    // earlier rewards, and `relics` the pools the run has drawn from so far
+    pub fn generate(
+        &self,
+        rng: &mut RunRng,
+        offset: &Offset,
+        relics: &mut RelicPools,
+        removals: u32,
+    ) -> Shop {
+        let floor = rng.floor();
+        let mut colored = [(Card::Invalid, Rarity::Common); 5];
+        for i in 0..colored.len() {
+            let kind = COLORED_KINDS[i];
+            let mut card = self.typed_card(&mut rng.card, offset, kind);
+            // only the second card of a kind rerolls, and only on a duplicate
+            while i > 0 && COLORED_KINDS[i - 1] == kind && colored[i - 1].0 == card.0 {
+                card = self.typed_card(&mut rng.card, offset, kind);
+            }
+            colored[i] = card;
+        }
+        let colorless = [
+            (self.colorless.uncommon.slice, Rarity::Uncommon),
+            (self.colorless.rare.slice, Rarity::Rare),
+        ]
+        .map(|(pool, rarity)| {
+            let len = pool.len() as u64;
+            (pool[rng.card.next_capped_u64(len) as usize], rarity)
+        });
+
+        let merchant = &mut rng.merchant;
+        let mut colored = colored.map(|(card, rarity)| ShopItem {
+            item: card,
+            price: self.price(merchant, card_price(rarity), 0.9, 1.1, 1.0) as u32,
+        });
+        let colorless = colorless.map(|(card, rarity)| ShopItem {
+            item: card,
+            price: self.markup(self.price(merchant, card_price(rarity), 0.9, 1.1, 1.2) as u32),
+        });
+        let sale = merchant.next_capped_u64(colored.len() as u64) as usize;
+        colored[sale].price /= 2;
+        for item in &mut colored {
+            item.price = self.markup(item.price);
+        }
+
+        let relics = core::array::from_fn(|i| {
+            let tier = if i == 2 {
+                Tier::Shop
+            } else {
+                match merchant.next_capped_u64(100) {
+                    0..=47 => Tier::Common,
+                    48..=81 => Tier::Uncommon,
+                    _ => Tier::Rare,
+                }
+            };
+            let relic = relics.take_last(tier, floor, true);
+            ShopItem {
+                item: relic,
+                price: self.markup(round(self.price(merchant, relic.price(), 0.95, 1.05, 1.0))),
+            }
+        });
+        let potions = [(); 3].map(|_| self.potions.generate(&mut rng.potion));
+        let potions = potions.map(|potion| {
+            let price = round(self.price(&mut rng.merchant, potion.price(), 0.95, 1.05, 1.0));
+            ShopItem {
+                item: potion,
+                price: self.markup(price),
+            }
+        });
+
+        Shop {
+            colored,
+            colorless,
+            sale,
+            relics,
+            potions,
+            removal: REMOVAL_PRICE + REMOVAL_INCREASE * removals,
+        }
+    }
+
+    // `getCardFromPool(rollRarity(), kind, true)`, which picks among the cards of that kind
+    // sorted by id, falling back to another rarity when there are none
+    fn typed_card(&self, rng: &mut Random, offset: &Offset, kind: Kind) -> (Card, Rarity) {
+        let fallbacks: &[Rarity] = match offset.shop_rarity(rng) {
+            Rarity::Rare => &[Rarity::Rare, Rarity::Uncommon, Rarity::Common],
+            Rarity::Uncommon => &[Rarity::Uncommon, Rarity::Common],
+            Rarity::Common => &[Rarity::Common, Rarity::Uncommon, Rarity::Rare],
+        };
+        for &rarity in fallbacks {
+            let mut cards = [Card::Invalid; MAX_KIND_LEN];
+            let mut len = 0;
+            for &card in self.cards.card_pool(rarity) {
+                if card.kind() == Some(kind) {
+                    cards[len] = card;
+                    len += 1;
+                }
+            }
+            if len > 0 {
+                let cards = &mut cards[..len];
+                cards.sort_unstable_by_key(|card| card.id());
+                return (cards[rng.next_capped_u64(len as u64) as usize], rarity);
+            }
+        }
+        unreachable!()
+    }
+
+    // the base price times the merchant's roll in `[low, high)`
+    fn price(&self, rng: &mut Random, base: u32, low: f32, high: f32, markup: f32) -> f32 {
+        base as f32 * (low + next_f32(rng) * (high - low)) * markup
+    }
+
+    // `applyDiscount(1.1F)` from ascension 16, which rounds the final price, sale included
+    fn markup(&self, price: u32) -> u32 {
+        if self.ascension >= 16 {
+            round(price as f32 * 1.1)
+        } else {
+            price
+        }
+    }
+}
+
+// `AbstractCard.getPrice`
+const fn card_price(rarity: Rarity) -> u32 {
+    match rarity {
+        Rarity::Common => 50,
+        Rarity::Uncommon => 75,
+        Rarity::Rare => 150,
+    }
+}
+
+// `MathUtils.round`
+fn round(price: f32) -> u32 {
+    (price + 0.5) as u32
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod shop_tests {
+    use crate::seed::Seed;
+
+    use super::*;
+
+    fn shop(seed: i64, character: Character) -> (Shop, RelicPools) {
+        let seed = Seed::from(seed);
+        let mut rng = RunRng::new(&seed);
+        let mut relics = RelicPools::new(character, &seed);
+        let shop =
+            Merchant::new(character, None).generate(&mut rng, &Offset::default(), &mut relics, 0);
+        (shop, relics)
+    }
+
+    #[test]
+    fn test_inventory() {
+        for seed in 0..50 {
+            let (shop, _) = shop(seed, Character::Ironclad);
+            let kinds = shop.colored.map(|item| item.item.kind());
+            assert_eq!(kinds, COLORED_KINDS.map(Some), "{shop:?}");
+            assert_ne!(shop.colored[0].item, shop.colored[1].item);
+            assert_ne!(shop.colored[2].item, shop.colored[3].item);
+            let colorless = ColorlessCards::new();
+            assert!(colorless.uncommon.slice.contains(&shop.colorless[0].item));
+            assert!(colorless.rare.slice.contains(&shop.colorless[1].item));
+            let rewarder: CardRewarder<'_, 1> = CardRewarder::new(Character::Ironclad, None);
+            for (i, item) in shop.colored.iter().enumerate() {
+                let rarity = [Rarity::Common, Rarity::Uncommon, Rarity::Rare]
+                    .into_iter()
+                    .find(|&rarity| rewarder.card_pool(rarity).contains(&item.item))
+                    .unwrap();
+                let base = card_price(rarity) as f32;
+                let base = if i == shop.sale { base / 2.0 } else { base };
+                assert!(item.price as f32 >= (base * 0.9).floor() - 1.0, "{item:?}");
+                assert!(item.price as f32 <= base * 1.1, "{item:?}");
+            }
+            assert_eq!(shop.relics[2].item.tier(), Tier::Shop);
+            assert!(shop
+                .relics
+                .iter()
+                .all(|item| item.item.character().is_none()
+                    || item.item.character() == Some(Character::Ironclad)));
+            assert_eq!(shop.removal, 75);
+            assert!(shop.sells(shop.colorless[1].item));
+        }
+    }
+
+    #[test]
+    fn test_relics_from_the_back() {
+        let seed = Seed::from(374388466716482469i64);
+        let (shop, relics) = shop(374388466716482469, Character::Ironclad);
+        let fresh = RelicPools::new(Character::Ironclad, &seed);
+        assert_eq!(Some(&shop.relics[2].item), fresh.pool(Tier::Shop).last());
+        assert_eq!(
+            relics.pool(Tier::Shop).len() + 1,
+            fresh.pool(Tier::Shop).len()
+        );
+        assert!(!shop.sells_relic(Relic::MawBank));
+    }
+
+    #[test]
+    fn test_merchant_rolls() {
+        // seven card prices, the sale, two relic tiers, three relic and three potion prices
+        let seed = Seed::from(42i64);
+        let mut rng = RunRng::new(&seed);
+        let mut relics = RelicPools::new(Character::Watcher, &seed);
+        Merchant::new(Character::Watcher, None).generate(
+            &mut rng,
+            &Offset::default(),
+            &mut relics,
+            2,
+        );
+        let mut expected = crate::rng::Stream::Merchant.rng(&seed);
+        expected.advance(16);
+        assert_eq!(rng.merchant.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn test_ascension_prices() {
+        let seed = Seed::from(7i64);
+        let prices = [0, 20].map(|ascension| {
+            let mut relics = RelicPools::new(Character::Silent, &seed);
+            Merchant::new(Character::Silent, None)
+                .with_ascension(ascension)
+                .generate(&mut RunRng::new(&seed), &Offset::default(), &mut relics, 1)
+        });
+        assert_eq!(
+            prices[0].colored.map(|item| item.item),
+            prices[1].colored.map(|item| item.item)
+        );
+        assert!(prices[1].relics[2].price > prices[0].relics[2].price);
+        let marked_up = |price: u32| (price as f32 * 1.1 + 0.5) as u32;
+        assert_eq!(
+            prices[1].colored.map(|item| item.price),
+            prices[0].colored.map(|item| marked_up(item.price))
+        );
+        // the sale card costs 25 before the markup: halved first, then rounded up from 27.5
+        assert_eq!(prices[1].sale, 3);
+        assert_eq!(
+            prices[1].colored.map(|item| item.price),
+            [50, 58, 74, 28, 75]
+        );
+        assert_eq!(prices[1].colorless.map(|item| item.price), [92, 185]);
+        assert_eq!(prices[1].relics.map(|item| item.price), [316, 173, 168]);
+        assert_eq!(prices[1].potions.map(|item| item.price), [54, 54, 57]);
+        assert_eq!(prices[0].removal, 100);
+    }
+}
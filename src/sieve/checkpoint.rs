@@ -0,0 +1,424 @@
+use core::ops::ControlFlow;
+use core::str::FromStr;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    write, writeln,
+};
+
+use crate::{
+    filter::SeedFilter,
+    seed::{Seed, SeedString},
+};
+
+use super::{sink::Sink, Sieve, Summary};
+
+const HEADER: &str = "spire-sieve checkpoint";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Malformed(usize),
+    ConfigMismatch {
+        saved: String,
+        requested: String,
+    },
+    RangeMismatch {
+        saved: (u64, u64),
+        requested: (u64, u64),
+    },
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+// `config` describes the filter, e.g. its search settings; a resume must present the same one.
+// The sieve never sees the config, so only the caller keeps it in step with the filter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    path: PathBuf,
+    config: String,
+    range: Option<(u64, u64)>,
+    last_completed: Option<u64>,
+    hits: Vec<Seed>,
+}
+
+impl Checkpoint {
+    pub fn new<P: Into<PathBuf>, C: Into<String>>(path: P, config: C) -> Self {
+        Self {
+            path: path.into(),
+            config: config.into(),
+            range: None,
+            last_completed: None,
+            hits: Vec::new(),
+        }
+    }
+
+    // resumes from `path` if it exists, otherwise starts fresh
+    pub fn open<P: Into<PathBuf>, C: Into<String>>(path: P, config: C) -> Result<Self, Error> {
+        let mut checkpoint = Self::new(path, config);
+        let contents = match fs::read_to_string(&checkpoint.path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(checkpoint),
+            Err(error) => return Err(error.into()),
+        };
+        let saved = Self::parse(&contents)?;
+        if saved.config != checkpoint.config {
+            return Err(Error::ConfigMismatch {
+                saved: saved.config,
+                requested: checkpoint.config,
+            });
+        }
+        checkpoint.range = Some(saved.range);
+        checkpoint.last_completed = saved.last_completed;
+        checkpoint.hits = saved.hits;
+        Ok(checkpoint)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn config(&self) -> &str {
+        &self.config
+    }
+
+    pub fn last_completed(&self) -> Option<u64> {
+        self.last_completed
+    }
+
+    pub fn hits(&self) -> &[Seed] {
+        &self.hits
+    }
+
+    // writes to a temporary file first so a kill mid-write keeps the previous checkpoint
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = String::new();
+        self.write(&mut contents)
+            .expect("writing to a String cannot fail");
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        fs::write(&temporary, contents)?;
+        fs::rename(&temporary, &self.path)
+    }
+
+    fn write(&self, out: &mut String) -> core::fmt::Result {
+        use core::fmt::Write;
+        let (start, end) = self.range.unwrap_or_default();
+        writeln!(out, "{HEADER}")?;
+        writeln!(out, "start {start}")?;
+        writeln!(out, "end {end}")?;
+        match self.last_completed {
+            Some(last) => writeln!(out, "last {last}")?,
+            None => writeln!(out, "last none")?,
+        }
+        writeln!(out, "config {}", self.config.len())?;
+        writeln!(out, "{}", self.config)?;
+        writeln!(out, "hits {}", self.hits.len())?;
+        for hit in &self.hits {
+            write!(out, "{}", SeedString::from(hit.clone()))?;
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    fn parse(contents: &str) -> Result<Saved, Error> {
+        let mut lines = Lines {
+            rest: contents,
+            line: 0,
+        };
+        if lines.next()? != HEADER {
+            return Err(Error::Malformed(lines.line));
+        }
+        let start = lines.value("start")?;
+        let end = lines.value("end")?;
+        let last_completed = match lines.field("last")? {
+            "none" => None,
+            last => Some(lines.parse(last)?),
+        };
+        let length: usize = lines.value("config")?;
+        let config = lines.take(length)?.to_string();
+        let count: usize = lines.value("hits")?;
+        let hits = (0..count)
+            .map(|_| {
+                let hit = lines.next()?;
+                let hit: SeedString = lines.parse(hit)?;
+                Ok(Seed::from(hit))
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(Saved {
+            config,
+            range: (start, end),
+            last_completed,
+            hits,
+        })
+    }
+}
+
+struct Saved {
+    config: String,
+    range: (u64, u64),
+    last_completed: Option<u64>,
+    hits: Vec<Seed>,
+}
+
+struct Lines<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Lines<'a> {
+    fn next(&mut self) -> Result<&'a str, Error> {
+        self.line += 1;
+        let (line, rest) = self
+            .rest
+            .split_once('\n')
+            .ok_or(Error::Malformed(self.line))?;
+        self.rest = rest;
+        Ok(line)
+    }
+
+    // the config may span several lines, so it is read by length
+    fn take(&mut self, length: usize) -> Result<&'a str, Error> {
+        self.line += 1;
+        let taken = self.rest.get(..length).ok_or(Error::Malformed(self.line))?;
+        let rest = self.rest[length..]
+            .strip_prefix('\n')
+            .ok_or(Error::Malformed(self.line))?;
+        self.line += taken.matches('\n').count();
+        self.rest = rest;
+        Ok(taken)
+    }
+
+    fn field(&mut self, key: &str) -> Result<&'a str, Error> {
+        let line = self.next()?;
+        line.strip_prefix(key)
+            .and_then(|value| value.strip_prefix(' '))
+            .ok_or(Error::Malformed(self.line))
+    }
+
+    fn value<T: FromStr>(&mut self, key: &str) -> Result<T, Error> {
+        let value = self.field(key)?;
+        self.parse(value)
+    }
+
+    fn parse<T: FromStr>(&self, value: &str) -> Result<T, Error> {
+        value.parse().map_err(|_| Error::Malformed(self.line))
+    }
+}
+
+// records each hit in the checkpoint before passing it on
+struct Record<'a, S: Sink> {
+    hits: &'a mut Vec<Seed>,
+    sink: S,
+}
+
+impl<'a, S: Sink> Sink for Record<'a, S> {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        self.hits.push(seed.clone());
+        self.sink.push(seed)
+    }
+
+    fn is_full(&self) -> bool {
+        self.sink.is_full()
+    }
+}
+
+impl<F: SeedFilter> Sieve<F> {
+    // saves the checkpoint after every `every` seeds and when the run ends;
+    // the summary only counts seeds tested by this call. Only the range is checked against the
+    // checkpoint: the filter is trusted to be the one its config describes, so derive the config
+    // from the filter's settings (`SearchSettings::to_json`) rather than naming it by hand
+    pub fn run_checkpointed<S: Sink>(
+        &self,
+        checkpoint: &mut Checkpoint,
+        every: u64,
+        sink: S,
+    ) -> Result<Summary, Error> {
+        self.checkpointed(checkpoint, every, sink, |sieve, start, end, sink| {
+            sieve.run_range(start, end, sink)
+        })
+    }
+
+    fn checkpointed<S: Sink>(
+        &self,
+        checkpoint: &mut Checkpoint,
+        every: u64,
+        sink: S,
+        run: impl Fn(&Self, u64, u64, &mut dyn Sink) -> Summary,
+    ) -> Result<Summary, Error> {
+        let requested = (self.start, self.end);
+        match checkpoint.range {
+            Some(saved) if saved != requested => {
+                return Err(Error::RangeMismatch { saved, requested })
+            }
+            _ => checkpoint.range = Some(requested),
+        }
+        let mut summary = Summary::default();
+        let mut start = match checkpoint.last_completed {
+            _ if self.start > self.end => return Ok(summary),
+            Some(last) if last >= self.end => return Ok(summary),
+            Some(last) => last + 1,
+            None => self.start,
+        };
+        let mut sink = sink;
+        let every = every.max(1);
+        loop {
+            let end = start.saturating_add(every - 1).min(self.end);
+            let mut record = Record {
+                hits: &mut checkpoint.hits,
+                sink: &mut sink,
+            };
+            let block = run(self, start, end, &mut record);
+            if block.tested == 0 {
+                return Ok(summary);
+            }
+            summary.tested += block.tested;
+            summary.matched += block.matched;
+            let last = start + block.tested - 1;
+            checkpoint.last_completed = Some(last);
+            checkpoint.save()?;
+            // `last < end` when the sink stopped the search early
+            if last == self.end || last < end {
+                return Ok(summary);
+            }
+            start = end + 1;
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<F: SeedFilter + Sync> Sieve<F> {
+    // `run_checkpointed` on the parallel sieve, with the same trust in the checkpoint's config
+    pub fn par_run_checkpointed<S: Sink>(
+        &self,
+        checkpoint: &mut Checkpoint,
+        every: u64,
+        sink: S,
+    ) -> Result<Summary, Error> {
+        self.checkpointed(checkpoint, every, sink, |sieve, start, end, sink| {
+            sieve.par_run_range(start, end, sink)
+        })
+    }
+}
+
+#[cfg(test)]
+mod checkpoint_tests {
+    use libgdx_xs128::{rng::Random, RandomXS128};
+    use std::{env, format};
+
+    use crate::{filter::RngFilter, sieve::sink::FirstN};
+
+    use super::*;
+
+    struct OneInFive;
+
+    impl RngFilter for OneInFive {
+        fn reject_rng(&self, rng: &mut Random) -> bool {
+            rng.next_capped_u64(5) != 0
+        }
+    }
+
+    const SIEVE: Sieve<OneInFive> = Sieve::new(1_000, 3_000, OneInFive);
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("spire-sieve-{}-{name}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_resume_after_interruption() {
+        let path = checkpoint_path("resume");
+        let mut expected = Vec::new();
+        let full = SIEVE.run(&mut expected);
+
+        // the first run is killed after 40 hits
+        let mut checkpoint = Checkpoint::open(&path, "one in five").unwrap();
+        let mut first = Vec::new();
+        let interrupted = SIEVE
+            .run_checkpointed(&mut checkpoint, 128, FirstN::new(40, &mut first))
+            .unwrap();
+        assert_eq!(interrupted.matched, 40);
+        assert_eq!(checkpoint.last_completed(), Some(first[39].seed as u64));
+
+        let mut checkpoint = Checkpoint::open(&path, "one in five").unwrap();
+        assert_eq!(checkpoint.hits(), first);
+        let mut rest = Vec::new();
+        let resumed = SIEVE
+            .run_checkpointed(&mut checkpoint, 128, &mut rest)
+            .unwrap();
+        assert_eq!(interrupted.tested + resumed.tested, full.tested);
+        assert_eq!(interrupted.matched + resumed.matched, full.matched);
+        assert_eq!(checkpoint.hits(), expected);
+        assert_eq!(checkpoint.last_completed(), Some(3_000));
+
+        // a finished search has nothing left to test
+        let mut checkpoint = Checkpoint::open(&path, "one in five").unwrap();
+        let finished = SIEVE
+            .run_checkpointed(&mut checkpoint, 128, Vec::new())
+            .unwrap();
+        assert_eq!(finished, Summary::default());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_checkpoint_matches_sequential() {
+        let path = checkpoint_path("parallel");
+        let mut expected = Vec::new();
+        let full = SIEVE.run(&mut expected);
+        let mut checkpoint = Checkpoint::open(&path, "one in five").unwrap();
+        let summary = SIEVE
+            .par_run_checkpointed(&mut checkpoint, 300, Vec::new())
+            .unwrap();
+        assert_eq!(summary, full);
+        assert_eq!(checkpoint.hits(), expected);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_reject_mismatched_resume() {
+        let path = checkpoint_path("mismatch");
+        let config = "{\n  \"filter\": \"one in five\"\n}";
+        let mut checkpoint = Checkpoint::open(&path, config).unwrap();
+        SIEVE
+            .run_checkpointed(&mut checkpoint, 500, FirstN::new(1, Vec::new()))
+            .unwrap();
+        assert_eq!(Checkpoint::open(&path, config).unwrap(), checkpoint);
+
+        assert!(matches!(
+            Checkpoint::open(&path, "one in six"),
+            Err(Error::ConfigMismatch { .. })
+        ));
+        let mut checkpoint = Checkpoint::open(&path, config).unwrap();
+        let other = Sieve::new(1_000, 4_000, OneInFive);
+        assert!(matches!(
+            other.run_checkpointed(&mut checkpoint, 500, Vec::new()),
+            Err(Error::RangeMismatch {
+                saved: (1_000, 3_000),
+                requested: (1_000, 4_000),
+            })
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_checkpoint() {
+        let path = checkpoint_path("malformed");
+        fs::write(&path, "spire-sieve checkpoint\nstart 1\nend two\n").unwrap();
+        assert!(matches!(
+            Checkpoint::open(&path, ""),
+            Err(Error::Malformed(3))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,6 +1,20 @@
-use std::println;
+use crate::{filter::SeedFilter, seed::Seed};
 
-use crate::{filter::SeedFilter, seed::SeedString};
+use self::sink::Sink;
+
+pub mod checkpoint;
+pub mod sink;
+
+#[cfg(feature = "parallel")]
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
+use core::ops::ControlFlow;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// seeds per parallel batch; each batch is reported in seed order before the next starts
+#[cfg(feature = "parallel")]
+pub const BATCH_SIZE: u64 = 1 << 20;
 
 pub struct Sieve<F: SeedFilter> {
     start: u64,
@@ -8,16 +22,82 @@ pub struct Sieve<F: SeedFilter> {
     filter: F,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub tested: u64,
+    pub matched: u64,
+}
+
 impl<F: SeedFilter> Sieve<F> {
     pub const fn new(start: u64, end: u64, filter: F) -> Self {
         Self { start, end, filter }
     }
 
-    pub fn run(&self) {
-        for seed in self.start..=self.end {
+    pub const fn start(&self) -> u64 {
+        self.start
+    }
+
+    pub const fn end(&self) -> u64 {
+        self.end
+    }
+
+    pub const fn filter(&self) -> &F {
+        &self.filter
+    }
+
+    pub fn run<S: Sink>(&self, mut sink: S) -> Summary {
+        self.run_range(self.start, self.end, &mut sink)
+    }
+
+    fn run_range<S: Sink + ?Sized>(&self, start: u64, end: u64, sink: &mut S) -> Summary {
+        let mut summary = Summary::default();
+        if sink.is_full() {
+            return summary;
+        }
+        for seed in start..=end {
+            summary.tested += 1;
             if !self.filter.reject(seed) {
-                println!("{}", SeedString::from(seed));
+                summary.matched += 1;
+                if sink.push(Seed::from(seed)).is_break() {
+                    break;
+                }
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<F: SeedFilter + Sync> Sieve<F> {
+    // reports the same seeds and summary as `run`
+    pub fn par_run<S: Sink>(&self, mut sink: S) -> Summary {
+        self.par_run_range(self.start, self.end, &mut sink)
+    }
+
+    fn par_run_range<S: Sink + ?Sized>(&self, mut start: u64, last: u64, sink: &mut S) -> Summary {
+        let mut summary = Summary::default();
+        if start > last || sink.is_full() {
+            return summary;
+        }
+        loop {
+            let end = start.saturating_add(BATCH_SIZE - 1).min(last);
+            let matches: Vec<u64> = (start..=end)
+                .into_par_iter()
+                .filter(|&seed| !self.filter.reject(seed))
+                .collect();
+            let flow = matches.into_iter().try_for_each(|seed| {
+                summary.matched += 1;
+                sink.push(Seed::from(seed)).map_break(|()| seed)
+            });
+            if let ControlFlow::Break(seed) = flow {
+                summary.tested += seed - start + 1;
+                return summary;
             }
+            summary.tested += end - start + 1;
+            if end == last {
+                return summary;
+            }
+            start = end + 1;
         }
     }
 }
@@ -26,7 +106,12 @@ impl<F: SeedFilter> Sieve<F> {
 #[cfg(test)]
 mod test_sieve {
     use super::*;
-    use crate::map::{filters::bottleneck::Bottleneck, _ONE_PATH_BURNING_ELITE_BOTTLENECKS, in_neighborhood::in_vec::InVec, out_neighborhood::out_vec::OutVec};
+    use crate::filter::RngFilter;
+    use crate::map::{
+        filters::bottleneck::Bottleneck, in_neighborhood::in_vec::InVec,
+        out_neighborhood::out_vec::OutVec, _ONE_PATH_BURNING_ELITE_BOTTLENECKS,
+    };
+    use crate::seed::SeedString;
 
     #[test]
     fn test_sieve_finds_bottleneck_seed() {
@@ -48,6 +133,96 @@ mod test_sieve {
 
         const FILTER: Bottleneck<InVec, OutVec> = Bottleneck::const_default();
         const SIEVE: Sieve<Bottleneck<InVec, OutVec>> = Sieve::new(START, END, FILTER);
-        SIEVE.run();
+        let mut seeds = alloc::vec::Vec::new();
+        let summary = SIEVE.run(&mut seeds);
+        assert_eq!(
+            summary,
+            Summary {
+                tested: 21,
+                matched: 1
+            }
+        );
+        assert_eq!(seeds, [Seed::from(_ONE_PATH_BURNING_ELITE_BOTTLENECKS[0])]);
+    }
+
+    struct OneInThree;
+
+    impl RngFilter for OneInThree {
+        fn reject_rng(&self, rng: &mut libgdx_xs128::rng::Random) -> bool {
+            use libgdx_xs128::RandomXS128;
+            rng.next_capped_u64(3) != 0
+        }
+    }
+
+    #[test]
+    fn test_sinks() {
+        use alloc::{string::String, vec::Vec};
+        use sink::{FirstN, ForEach, WriteSink};
+
+        const SIEVE: Sieve<OneInThree> = Sieve::new(1, 300, OneInThree);
+        let expected: Vec<Seed> = (1u64..=300)
+            .filter(|&seed| !OneInThree.reject(seed))
+            .map(Seed::from)
+            .collect();
+
+        let mut seeds = Vec::new();
+        let summary = SIEVE.run(&mut seeds);
+        let matched = expected.len() as u64;
+        assert_eq!(
+            summary,
+            Summary {
+                tested: 300,
+                matched
+            }
+        );
+        assert_eq!(seeds, expected);
+
+        let mut first = FirstN::new(5, Vec::new());
+        let summary = SIEVE.run(&mut first);
+        assert_eq!(first.into_inner(), expected[..5]);
+        let tested = expected[4].seed as u64;
+        assert_eq!(summary, Summary { tested, matched: 5 });
+
+        let mut none = FirstN::new(0, Vec::new());
+        let summary = SIEVE.run(&mut none);
+        assert!(none.into_inner().is_empty());
+        assert_eq!(summary, Summary::default());
+
+        let mut count = 0;
+        SIEVE.run(ForEach(|_| count += 1));
+        assert_eq!(count, expected.len());
+
+        let mut lines = WriteSink::new(Vec::new());
+        SIEVE.run(&mut lines);
+        let lines = String::from_utf8(lines.finish().unwrap()).unwrap();
+        let expected_lines = expected
+            .iter()
+            .map(|seed| std::format!("{}", SeedString::from(seed.clone())));
+        assert!(lines.lines().eq(expected_lines));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_sieve_matches_sequential_order() {
+        use alloc::vec::Vec;
+        use sink::FirstN;
+
+        const START: u64 = BATCH_SIZE - 500;
+        const END: u64 = BATCH_SIZE + 1_500;
+        const SIEVE: Sieve<OneInThree> = Sieve::new(START, END, OneInThree);
+        let mut expected = Vec::new();
+        let summary = SIEVE.run(&mut expected);
+        let mut seeds = Vec::new();
+        assert_eq!(SIEVE.par_run(&mut seeds), summary);
+        assert_eq!(seeds, expected);
+
+        // stop in the first batch, then in the second
+        for n in [0, 1, 300] {
+            let mut expected = FirstN::new(n, Vec::new());
+            let summary = SIEVE.run(&mut expected);
+            let mut seeds = FirstN::new(n, Vec::new());
+            assert_eq!(SIEVE.par_run(&mut seeds), summary);
+            assert_eq!(seeds.into_inner(), expected.into_inner());
+        }
     }
 }
@@ -0,0 +1,132 @@
+use core::ops::ControlFlow;
+
+use alloc::vec::Vec;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    println,
+};
+
+use crate::seed::{Seed, SeedString};
+
+pub trait Sink {
+    // `Break` stops the search after this seed
+    fn push(&mut self, seed: Seed) -> ControlFlow<()>;
+    // `true` when the sink takes no more seeds, so the search stops before testing one
+    fn is_full(&self) -> bool {
+        false
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        (**self).push(seed)
+    }
+
+    fn is_full(&self) -> bool {
+        (**self).is_full()
+    }
+}
+
+impl Sink for Vec<Seed> {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        Vec::push(self, seed);
+        ControlFlow::Continue(())
+    }
+}
+
+// prints each seed to stdout
+pub struct Print;
+
+impl Sink for Print {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        println!("{}", SeedString::from(seed));
+        ControlFlow::Continue(())
+    }
+}
+
+pub struct ForEach<F: FnMut(Seed)>(pub F);
+
+impl<F: FnMut(Seed)> Sink for ForEach<F> {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        (self.0)(seed);
+        ControlFlow::Continue(())
+    }
+}
+
+// writes one seed string per line; the search stops at the first io error
+pub struct WriteSink<W: Write> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> WriteSink<W> {
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl WriteSink<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Sink for WriteSink<W> {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        match writeln!(self.writer, "{}", SeedString::from(seed)) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(error) => {
+                self.error = Some(error);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+// forwards at most `remaining` seeds, then stops the search
+pub struct FirstN<S: Sink> {
+    sink: S,
+    remaining: usize,
+}
+
+impl<S: Sink> FirstN<S> {
+    pub const fn new(n: usize, sink: S) -> Self {
+        Self { sink, remaining: n }
+    }
+
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+}
+
+impl<S: Sink> Sink for FirstN<S> {
+    fn push(&mut self, seed: Seed) -> ControlFlow<()> {
+        if self.remaining == 0 {
+            return ControlFlow::Break(());
+        }
+        self.remaining -= 1;
+        self.sink.push(seed)?;
+        if self.remaining == 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.remaining == 0 || self.sink.is_full()
+    }
+}
@@ -1,8 +1,12 @@
 use strum::EnumCount;
 use strum_macros::{EnumCount, EnumIter, FromRepr};
 
-use crate::character::Character;
+use crate::{card::Card, character::Character};
 
+#[cfg(feature = "json")]
+pub mod preferences;
+
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr, EnumIter, EnumCount)]
 pub enum UnlockLevel {
     Zero,
@@ -13,7 +17,8 @@ pub enum UnlockLevel {
     Five,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Unlocks {
     levels: [UnlockLevel; Character::COUNT],
 }
@@ -29,3 +34,107 @@ impl Unlocks {
         Self { levels }
     }
 }
+
+impl Unlocks {
+    pub const fn level(&self, character: Character) -> UnlockLevel {
+        self.levels[character as usize]
+    }
+}
+
+// the card bundles among each character's five `UnlockTracker` bundles; the others unlock
+// relics, which is the last two for every character but the Silent, whose third is relics
+const CARD_UNLOCKS: [[(UnlockLevel, [Card; 3]); 3]; Character::COUNT] = {
+    use Card::*;
+    use UnlockLevel::*;
+    [
+        [
+            (One, [HeavyBlade, SpotWeakness, LimitBreak]),
+            (Two, [WildStrike, Evolve, Immolate]),
+            (Three, [Havoc, Sentinel, Exhume]),
+        ],
+        [
+            (One, [Bane, Catalyst, CorpseExplosion]),
+            (Two, [CloakAndDagger, Accuracy, StormOfSteel]),
+            (Four, [Concentrate, Setup, GrandFinale]),
+        ],
+        [
+            (One, [Rebound, Equilibrium, EchoForm]),
+            (Two, [Turbo, Sunder, MeteorStrike]),
+            (Three, [Hyperbeam, Recycle, CoreSurge]),
+        ],
+        [
+            (One, [Prostrate, Blasphemy, Devotion]),
+            (Two, [ForeignInfluence, Alpha, MentalFortress]),
+            (Three, [SpiritShield, Wish, Foresight]),
+        ],
+    ]
+};
+
+impl UnlockLevel {
+    pub const fn is_locked(self, character: Character, card: Card) -> bool {
+        let bundles = &CARD_UNLOCKS[character as usize];
+        let mut i = 0;
+        while i < bundles.len() {
+            let (level, cards) = &bundles[i];
+            if (self as usize) < (*level as usize) {
+                let mut j = 0;
+                while j < cards.len() {
+                    if cards[j] as usize == card as usize {
+                        return true;
+                    }
+                    j += 1;
+                }
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod unlock_tests {
+    use strum::IntoEnumIterator;
+
+    use crate::card::pool::CharacterCards;
+
+    use super::*;
+
+    #[test]
+    fn test_locked_cards_belong_to_character() {
+        for character in Character::iter() {
+            let all = CharacterCards::new(character).all.slice;
+            let locked = |level: UnlockLevel| {
+                all.iter()
+                    .filter(|&&card| level.is_locked(character, card))
+                    .count()
+            };
+            let counts: [usize; UnlockLevel::COUNT] =
+                core::array::from_fn(|level| locked(UnlockLevel::from_repr(level).unwrap()));
+            let expected = match character {
+                Character::Silent => [9, 6, 3, 3, 0, 0],
+                _ => [9, 6, 3, 0, 0, 0],
+            };
+            assert_eq!(counts, expected, "{character:?}");
+        }
+    }
+
+    #[test]
+    fn test_last_card_unlock() {
+        use UnlockLevel::*;
+        for (character, card, unlocked_at) in [
+            (Character::Ironclad, Card::Exhume, Three),
+            (Character::Silent, Card::GrandFinale, Four),
+            (Character::Defect, Card::CoreSurge, Three),
+            (Character::Watcher, Card::Wish, Three),
+        ] {
+            for level in UnlockLevel::iter() {
+                let locked = (level as usize) < (unlocked_at as usize);
+                assert_eq!(
+                    level.is_locked(character, card),
+                    locked,
+                    "{character:?} {level:?}"
+                );
+            }
+        }
+    }
+}
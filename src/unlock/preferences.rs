@@ -0,0 +1,121 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use std::{fs, io, path::Path};
+
+use strum::{EnumCount, IntoEnumIterator};
+
+use crate::character::Character;
+
+use super::{UnlockLevel, Unlocks};
+
+pub const UNLOCK_PROGRESS: &str = "STSUnlockProgress";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Json(serde_json::Error),
+    MissingCharacter(Character),
+    MalformedLevel(Character, String),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+// the player class names the game prefixes its keys with
+const fn player_class(character: Character) -> &'static str {
+    match character {
+        Character::Ironclad => "IRONCLAD",
+        Character::Silent => "THE_SILENT",
+        Character::Defect => "DEFECT",
+        Character::Watcher => "WATCHER",
+    }
+}
+
+impl Unlocks {
+    // reads `STSUnlockProgress` from the game's `preferences` directory
+    pub fn from_preferences<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        let contents = fs::read_to_string(dir.as_ref().join(UNLOCK_PROGRESS))?;
+        Self::from_unlock_progress(&contents)
+    }
+
+    pub fn from_unlock_progress(contents: &str) -> Result<Self, Error> {
+        let preferences: BTreeMap<String, serde_json::Value> = serde_json::from_str(contents)?;
+        let mut levels = [UnlockLevel::Zero; Character::COUNT];
+        for character in Character::iter() {
+            let key = [player_class(character), "UnlockLevel"].concat();
+            let value = preferences
+                .get(&key)
+                .ok_or(Error::MissingCharacter(character))?;
+            // the game stores every value as a string
+            let level = match value {
+                serde_json::Value::String(level) => level.trim().parse::<usize>().ok(),
+                _ => None,
+            };
+            levels[character as usize] = level
+                .and_then(UnlockLevel::from_repr)
+                .ok_or_else(|| Error::MalformedLevel(character, value.to_string()))?;
+        }
+        Ok(Self::new(levels))
+    }
+}
+
+#[cfg(test)]
+mod preferences_tests {
+    use super::*;
+
+    #[test]
+    fn test_full_unlocks() {
+        let unlocks = Unlocks::from_preferences(".unlock_tests/full").unwrap();
+        assert_eq!(unlocks, Unlocks::full());
+    }
+
+    #[test]
+    fn test_partial_unlocks() {
+        let unlocks = Unlocks::from_preferences(".unlock_tests/fresh").unwrap();
+        use UnlockLevel::*;
+        assert_eq!(unlocks, Unlocks::new([Two, One, Zero, Zero]));
+        assert_eq!(unlocks.level(Character::Silent), One);
+    }
+
+    #[test]
+    fn test_unlock_errors() {
+        assert!(matches!(
+            Unlocks::from_preferences(".unlock_tests/no_watcher"),
+            Err(Error::MissingCharacter(Character::Watcher))
+        ));
+        assert!(matches!(
+            Unlocks::from_preferences(".unlock_tests/malformed"),
+            Err(Error::MalformedLevel(Character::Silent, value)) if value == "\"five\""
+        ));
+        assert!(matches!(
+            Unlocks::from_preferences(".unlock_tests/missing"),
+            Err(Error::Io(error)) if error.kind() == io::ErrorKind::NotFound
+        ));
+        assert!(matches!(
+            Unlocks::from_unlock_progress("IRONCLADUnlockLevel=5"),
+            Err(Error::Json(_))
+        ));
+        let full = fs::read_to_string(".unlock_tests/full/STSUnlockProgress").unwrap();
+        for level in ["6", "999", "-1"] {
+            let contents = full.replace(
+                r#""IRONCLADUnlockLevel": "5""#,
+                &[r#""IRONCLADUnlockLevel": ""#, level, "\""].concat(),
+            );
+            assert!(matches!(
+                Unlocks::from_unlock_progress(&contents),
+                Err(Error::MalformedLevel(Character::Ironclad, value)) if value.trim_matches('"') == level
+            ));
+        }
+    }
+}